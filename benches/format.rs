@@ -0,0 +1,86 @@
+//! Compares formatting many files while reusing one arena allocator, which is
+//! what the `Formatter` does, against creating a new allocator per file. Both
+//! sides parse and format with the same options so only the allocator
+//! differs.
+//!
+//! Run with `cargo bench --bench format`.
+
+use std::hint::black_box;
+use std::time::Duration;
+use std::time::Instant;
+
+use dprint_plugin_oxc::SourceType;
+use oxc_allocator::Allocator;
+use oxc_formatter::JsFormatOptions;
+use oxc_parser::ParseOptions;
+use oxc_parser::Parser;
+
+const FILE_COUNT: usize = 2_000;
+
+const SNIPPET: &str = r#"
+import { readFile } from "node:fs/promises";
+
+export interface Item { id: number; name: string; tags?: string[] }
+
+export async function loadItems(path: string): Promise<Item[]> {
+  const text = await readFile(path, "utf8");
+  return JSON.parse(text).map((item: Item, index: number) => ({ ...item, id: item.id ?? index, tags: item.tags ?? [] }));
+}
+
+export class Store {
+  private items = new Map<number, Item>();
+  add(item: Item) { this.items.set(item.id, item); return this }
+  find(predicate: (item: Item) => boolean) { for (const item of this.items.values()) { if (predicate(item)) return item } return undefined }
+}
+"#;
+
+fn main() {
+  let small_file = SNIPPET.to_string();
+  let large_file = SNIPPET.repeat(50);
+  let source_type = SourceType::ts();
+  let mut allocator = Allocator::default();
+
+  for (name, text) in [("small files", &small_file), ("large files", &large_file)] {
+    let file_count = FILE_COUNT * small_file.len() / text.len();
+    let new_allocator = measure(file_count, || format(&Allocator::default(), source_type, text));
+    let reused_allocator = measure(file_count, || {
+      format(&allocator, source_type, text);
+      allocator.reset();
+    });
+    println!(
+      "{name} ({file_count} x {} bytes): new allocator {:?}, reused allocator {:?} ({:.2}x)",
+      text.len(),
+      new_allocator,
+      reused_allocator,
+      new_allocator.as_secs_f64() / reused_allocator.as_secs_f64()
+    );
+  }
+}
+
+fn measure(iterations: usize, mut action: impl FnMut()) -> Duration {
+  // warm up
+  for _ in 0..iterations / 10 {
+    action();
+  }
+  let start = Instant::now();
+  for _ in 0..iterations {
+    action();
+  }
+  start.elapsed()
+}
+
+/// Parses and formats the text with the parse options the `Formatter` uses.
+fn format(allocator: &Allocator, source_type: SourceType, text: &str) {
+  let parse_options = ParseOptions {
+    preserve_parens: false,
+    ..Default::default()
+  };
+  let parsed = Parser::new(allocator, text, source_type)
+    .with_options(parse_options)
+    .parse();
+  let output = oxc_formatter::format_program(allocator, &parsed.program, JsFormatOptions::default())
+    .print()
+    .unwrap()
+    .into_code();
+  black_box(output);
+}
@@ -0,0 +1,15 @@
+// Exposes the Oxc version from the oxc git tag in Cargo.toml (ex. `crates_v0.146.0`)
+// as `OXC_VERSION` so errors can say which version of Oxc was used.
+fn main() {
+  println!("cargo:rerun-if-changed=Cargo.toml");
+  let cargo_toml = std::fs::read_to_string("Cargo.toml").unwrap();
+  let version = cargo_toml
+    .lines()
+    .filter(|line| line.contains("https://github.com/oxc-project/oxc"))
+    .find_map(|line| {
+      let tag = line.split("tag = \"").nth(1)?.split('"').next()?;
+      Some(tag.strip_prefix("crates_v").unwrap_or(tag).to_string())
+    })
+    .unwrap_or_else(|| "unknown".to_string());
+  println!("cargo:rustc-env=OXC_VERSION={}", version);
+}
@@ -0,0 +1,386 @@
+// This file is generated from the plugin's configuration types. Do not edit it by hand.
+// Run `UPDATE_SCHEMA=1 cargo test --test schema` to regenerate it.
+
+/** Configuration for the `"oxc"` key of a dprint configuration file. */
+export interface Configuration {
+  /**
+   * The kind of line ending.
+   *
+   * @default "lf"
+   */
+  lineEnding?: "lf" | "crlf" | "cr" | "auto" | "system";
+  /**
+   * The number of characters for an indent.
+   *
+   * @default 2
+   */
+  indentWidth?: number;
+  /**
+   * Whether to use tabs or spaces.
+   *
+   * @default "space"
+   */
+  indentStyle?: "tab" | "space";
+  /**
+   * How many characters can be written on a single line.
+   *
+   * @default 80
+   */
+  lineWidth?: number;
+  /**
+   * Whether to use semicolons.
+   *
+   * @default "always"
+   */
+  semicolons?: "always" | "asNeeded";
+  /**
+   * The type of quote used when representing string literals.
+   *
+   * @default "double"
+   */
+  quoteStyle?: "double" | "single";
+  /**
+   * The type of quote used in JSX attributes.
+   *
+   * @default "double"
+   */
+  jsxQuoteStyle?: "double" | "single";
+  /**
+   * Whether property names inside objects should be quoted.
+   *
+   * @default "asNeeded"
+   */
+  quoteProperties?: "asNeeded" | "preserve" | "consistent";
+  /**
+   * Whether to add non-necessary parentheses to arrow functions.
+   *
+   * @default "always"
+   */
+  arrowParentheses?: "always" | "asNeeded";
+  /**
+   * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
+   *
+   * @default "all"
+   */
+  trailingCommas?: "all" | "es5" | "none";
+  /**
+   * Surround the inner contents of some braces with spaces.
+   *
+   * @default true
+   */
+  bracketSpacing?: boolean;
+  /**
+   * Place the last angle bracket in JSX tags on the same line as the last attribute.
+   *
+   * @default false
+   */
+  bracketSameLine?: boolean;
+  /**
+   * How JSX/TSX attributes are positioned.
+   *
+   * @default "auto"
+   */
+  attributePosition?: "auto" | "multiline";
+  /**
+   * Whether to expand object and array literals to multiple lines.
+   *
+   * @default "auto"
+   */
+  expand?: "auto" | "never";
+  /**
+   * Controls the position of operators in binary expressions when they wrap lines.
+   *
+   * @default "end"
+   */
+  operatorPosition?: "start" | "end";
+  /**
+   * [EXPERIMENTAL - NOT FULLY SUPPORTED] Try prettier's new ternary formatting before it becomes the default behavior.
+   *
+   * @default false
+   */
+  experimentalTernaries?: boolean;
+  /**
+   * Whether HTML-in-JS templates always use hard line breaks for wrapping.
+   *
+   * @default false
+   */
+  htmlWhitespaceSensitivityIgnore?: boolean;
+  /**
+   * [EXPERIMENTAL] Sort import statements. When set, enables import sorting with the specified options.
+   */
+  experimentalSortImports?: {
+    /**
+     * Partition imports by newlines.
+     *
+     * @default false
+     */
+    partitionByNewline?: boolean;
+    /**
+     * Partition imports by comments.
+     *
+     * @default false
+     */
+    partitionByComment?: boolean;
+    /**
+     * Sort side effects imports.
+     *
+     * @default false
+     */
+    sortSideEffects?: boolean;
+    /**
+     * Sort order (ascending or descending).
+     *
+     * @default "asc"
+     */
+    order?: "asc" | "desc";
+    /**
+     * Ignore case when sorting.
+     *
+     * @default true
+     */
+    ignoreCase?: boolean;
+    /**
+     * Whether to insert blank lines between different import groups.
+     *
+     * @default true
+     */
+    newlinesBetween?: boolean;
+    /**
+     * Patterns to identify internal imports.
+     *
+     * @default []
+     */
+    internalPattern?: string[];
+    /**
+     * Custom grouping of imports. Each inner array represents a group and each entry is a selector optionally prefixed by modifiers (ex. `type-external`), `unknown`, or the name of a custom group.
+     *
+     * @default [["builtin"], ["external"], ["internal", "subpath"], ["parent", "sibling", "index"], ["style"], ["unknown"]]
+     */
+    groups?: string[][];
+    /**
+     * Definitions for custom import groups referenced by groups.
+     *
+     * @default []
+     */
+    customGroups?: {
+      /**
+       * The custom group name.
+       */
+      groupName?: string;
+      /**
+       * Glob patterns that match import sources.
+       *
+       * @default []
+       */
+      elementNamePattern?: string[];
+      /**
+       * The import selector that must match.
+       */
+      selector?: "type" | "side_effect_style" | "side_effect" | "style" | "index" | "sibling" | "parent" | "subpath" | "internal" | "builtin" | "external" | "import";
+      /**
+       * Import modifiers that must all be present.
+       *
+       * @default []
+       */
+      modifiers?: ("side_effect" | "type" | "value" | "default" | "wildcard" | "named")[];
+    }[];
+  };
+  /**
+   * [EXPERIMENTAL] Enable Tailwind CSS class sorting. When set, enables class sorting with the specified options.
+   */
+  experimentalTailwindcss?: {
+    /**
+     * Custom functions to sort classes in.
+     *
+     * @default []
+     */
+    functions?: string[];
+    /**
+     * Custom attributes to sort classes in.
+     *
+     * @default []
+     */
+    attributes?: string[];
+    /**
+     * Preserve whitespace in class strings.
+     *
+     * @default false
+     */
+    preserveWhitespace?: boolean;
+  };
+  /**
+   * Enable JSDoc comment formatting with the specified options.
+   */
+  jsdoc?: {
+    /**
+     * Capitalize the first letter of tag descriptions.
+     *
+     * @default true
+     */
+    capitalizeDescriptions?: boolean;
+    /**
+     * How to format JSDoc comment blocks.
+     *
+     * @default "singleLine"
+     */
+    commentLineStrategy?: "singleLine" | "multiline" | "keep";
+    /**
+     * Add blank lines between different tag groups.
+     *
+     * @default false
+     */
+    separateTagGroups?: boolean;
+    /**
+     * Add a blank line between the last @param and @returns.
+     *
+     * @default false
+     */
+    separateReturnsFromParam?: boolean;
+    /**
+     * Add spaces inside JSDoc type braces.
+     *
+     * @default false
+     */
+    bracketSpacing?: boolean;
+    /**
+     * Add a trailing dot to descriptions.
+     *
+     * @default false
+     */
+    descriptionWithDot?: boolean;
+    /**
+     * Append default values to @param descriptions.
+     *
+     * @default true
+     */
+    addDefaultToDescription?: boolean;
+    /**
+     * Use fenced code blocks for code without a language tag.
+     *
+     * @default false
+     */
+    preferCodeFences?: boolean;
+    /**
+     * How JSDoc description lines wrap at the configured line width.
+     *
+     * @default "greedy"
+     */
+    lineWrappingStyle?: "greedy" | "balance";
+    /**
+     * Emit an @description tag instead of an inline description.
+     *
+     * @default false
+     */
+    descriptionTag?: boolean;
+    /**
+     * Preserve indentation in unparsable @example code.
+     *
+     * @default false
+     */
+    keepUnparsableExampleIndent?: boolean;
+  };
+  /**
+   * What to do with the byte order mark of UTF-8 files. UTF-16 files always keep theirs.
+   *
+   * @default "preserve"
+   */
+  byteOrderMark?: "preserve" | "remove";
+  /**
+   * Additional file extensions to format, mapped to the kind of source they contain (ex. `{ "es6": "js", "d.mts": "dts" }`). When several extensions match a file, the longest one is used.
+   */
+  fileExtensions?: Record<string, "js" | "jsx" | "ts" | "tsx" | "dts" | {
+    /**
+     * The language of the source.
+     */
+    language?: "js" | "jsx" | "ts" | "tsx" | "dts";
+    /**
+     * How the source is parsed. Defaults to detecting whether the file is a module.
+     */
+    moduleKind?: "module" | "script" | "unambiguous" | "commonjs";
+  }>;
+  /**
+   * Exact file names to format, mapped to the kind of source they contain (ex. `{ "Jakefile": "js" }`). These take precedence over file extensions.
+   */
+  fileNames?: Record<string, "js" | "jsx" | "ts" | "tsx" | "dts" | {
+    /**
+     * The language of the source.
+     */
+    language?: "js" | "jsx" | "ts" | "tsx" | "dts";
+    /**
+     * How the source is parsed. Defaults to detecting whether the file is a module.
+     */
+    moduleKind?: "module" | "script" | "unambiguous" | "commonjs";
+  }>;
+  /**
+   * Whether to format files with an unknown extension (ex. executables in a `bin` directory) based on their shebang. Node scripts are formatted as JavaScript and Deno, ts-node, tsx, and Bun scripts as TypeScript.
+   *
+   * @default false
+   */
+  detectShebang?: boolean;
+  /**
+   * How `.js` and `.jsx` files are parsed. Files with a `.mjs` or `.cjs` extension are always parsed as ES or CommonJS modules.
+   *
+   * @default "unambiguous"
+   */
+  jsModuleKind?: "module" | "script" | "unambiguous" | "commonjs";
+  /**
+   * Whether to parse `.js` files as JavaScript with JSX, or globs of the `.js` files to parse with JSX (ex. `["src/components/**"]`). dprint doesn't tell plugins where the configuration file is, so globs that don't start with `/` or `**` match the end of a file's path (ex. `src/*.js` matches `src/App.js` in any directory). Files that fail to parse with JSX are parsed again without it.
+   *
+   * @default false
+   */
+  jsxInJs?: boolean | string[];
+  /**
+   * Whether to allow `return` statements outside of functions (ex. in CommonJS modules or build tool snippets).
+   *
+   * @default false
+   */
+  allowReturnOutsideFunction?: boolean;
+  /**
+   * Whether to allow V8 intrinsics such as `%DebugPrint(foo)`.
+   *
+   * @default false
+   */
+  allowV8Intrinsics?: boolean;
+  /**
+   * Not supported. Oxc's formatter needs parenthesized expressions removed while parsing, so only `false` is accepted and `true` reports a diagnostic. Parentheses that are needed are still printed.
+   *
+   * @default false
+   */
+  preserveParens?: boolean;
+  /**
+   * How deeply brackets and syntax nodes may be nested (ex. in generated arrays, JSX, or long chains of binary expressions) before the file is not formatted, which prevents the formatter from running out of stack space.
+   *
+   * @default 500
+   */
+  maxNestingDepth?: number;
+  /**
+   * What to do with files nested deeper than `maxNestingDepth`.
+   *
+   * @default "error"
+   */
+  nestingDepthExceeded?: "error" | "skip";
+  /**
+   * Whether to format the output a second time to check that formatting is stable, since Oxc occasionally produces output that changes when formatted again.
+   *
+   * @default "off"
+   */
+  verifyIdempotency?: "off" | "secondPass" | "error";
+  /**
+   * Whether to parse the formatted output and check that its syntax tree matches the input's, ignoring parentheses, trivia, and intended changes such as sorted imports. The file is not formatted when they differ, which guards against formatter bugs that change the meaning of the code.
+   *
+   * @default false
+   */
+  verifySemantics?: boolean;
+  /**
+   * Whether to check that the formatted output has the same comments as the input, so lost or duplicated comments are reported as an error instead of written. Only the number of JSDoc comments is checked when `jsdoc` is enabled since they're reformatted.
+   *
+   * @default false
+   */
+  verifyComments?: boolean;
+  /**
+   * Whether the plugin caches formatted files in memory so files that are sent again without changes (ex. by editors) are not formatted again. The configurations share a cache of up to 16 MB.
+   *
+   * @default true
+   */
+  cache?: boolean;
+}
@@ -1,2 +1,4 @@
+export type { Configuration } from "./config";
+
 /** Gets a buffer representing the Wasm module. */
 export function getBuffer(): ArrayBuffer;
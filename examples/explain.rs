@@ -0,0 +1,28 @@
+//! Prints the effective configuration of the plugin and where each value
+//! came from.
+//!
+//! Usage: cargo run --example explain -- [path/to/dprint.json]
+
+use dprint_core::configuration::ConfigKeyMap;
+use dprint_core::configuration::ConfigKeyValue;
+use dprint_core::configuration::resolve_global_config;
+use dprint_plugin_oxc::configuration::explain_config;
+
+fn main() {
+  let path = std::env::args().nth(1).unwrap_or_else(|| "dprint.json".to_string());
+  let text = std::fs::read_to_string(&path).unwrap_or_else(|err| panic!("Failed reading {}: {:#}", path, err));
+  // dprint allows comments and trailing commas in its configuration file
+  let mut config: ConfigKeyMap = jsonc_parser::parse_to_serde_value(&text, &Default::default())
+    .unwrap_or_else(|err| panic!("Failed parsing {}: {:#}", path, err));
+
+  let plugin_config = match config.shift_remove("oxc") {
+    Some(ConfigKeyValue::Object(obj)) => obj,
+    _ => ConfigKeyMap::new(),
+  };
+  let global_config_result = resolve_global_config(&mut config);
+  for diagnostic in &global_config_result.diagnostics {
+    eprintln!("error: {} ({})", diagnostic.message, diagnostic.property_name);
+  }
+
+  print!("{}", explain_config(plugin_config, &global_config_result.config));
+}
@@ -113,7 +113,7 @@ function buildFixPrompt(options: AiFixOptions): string {
     ``,
     `Rules:`,
     `1. If an option was RENAMED or REMOVED in \`JsFormatOptions\`, update the mapping in \`src/format_text.rs\` (and remove/rename the corresponding plugin config in the other files if it no longer exists upstream).`,
-    `2. If an option was ADDED in \`JsFormatOptions\`, expose it as a new plugin config option across ALL of: \`configuration.rs\`, \`resolve_config.rs\`, \`format_text.rs\`, and \`deployment/schema.json\`, AND add a spec test under \`tests/specs/\` that exercises it. Match the existing naming conventions (Rust snake_case fields, camelCase dprint keys).`,
+    `2. If an option was ADDED in \`JsFormatOptions\`, expose it as a new plugin config option across ALL of: \`configuration.rs\`, \`resolve_config.rs\`, \`format_text.rs\`, \`schema.rs\`, and \`explain.rs\`, then regenerate \`deployment/schema.json\` and \`deployment/npm/config.d.ts\` with \`UPDATE_SCHEMA=1 cargo test --test schema\` (never edit those two generated files by hand), AND add a spec test under \`tests/specs/\` that exercises it. Match the existing naming conventions (Rust snake_case fields, camelCase dprint keys).`,
     `3. Do NOT edit \`README.md\`. Its config documentation is maintained separately, so leave it untouched.`,
     `4. Preserve the existing code style. Keep non-test code above test modules. New comments start lowercase unless multiple sentences.`,
     `5. When done, ALL of these must pass (CI denies clippy warnings, and the wasm build is what actually ships) — iterate until they are all clean:`,
@@ -145,7 +145,9 @@ function describeWiring(): string {
     `- \`src/format_text.rs\` -> \`build_format_options\` maps this plugin's \`Configuration\` onto oxc's \`JsFormatOptions\` (direct field assignment) and its option enums (\`ArrowParentheses\`, \`AttributePosition\`, \`EmbeddedLanguageFormatting\`, \`Expand\`, \`OperatorPosition\`, \`QuoteProperties\`, \`QuoteStyle\`, \`Semicolons\`, \`TrailingCommas\`, \`SortOrder\`), the \`oxc_formatter_core\` types (\`IndentStyle\`, \`IndentWidth\`, \`LineEnding\`, \`LineWidth\`), and nested option structs (\`SortImportsOptions\`, \`SortTailwindcssOptions\`, \`CustomGroupDefinition\`, \`GroupEntry\`).`,
     `- \`src/configuration/configuration.rs\` -> the plugin's own \`Configuration\` struct and enums.`,
     `- \`src/configuration/resolve_config.rs\` -> reads each dprint config key (camelCase) into \`Configuration\`.`,
-    `- \`deployment/schema.json\` -> the JSON schema of config options shown to users.`,
+    `- \`src/configuration/schema.rs\` -> the description, type, and default of each config option.`,
+    `- \`src/configuration/explain.rs\` -> reports the effective value of each config option and where it came from.`,
+    `- \`deployment/schema.json\` and \`deployment/npm/config.d.ts\` -> generated from \`schema.rs\` by \`UPDATE_SCHEMA=1 cargo test --test schema\`; \`cargo test\` fails when they are out of date.`,
     `- \`tests/specs/*.txt\` -> spec tests (run by \`dprint_development::run_specs\` via \`tests/test.rs\`) that exercise each config option.`,
   ].join("\n");
 }
@@ -224,7 +226,7 @@ function buildReviewPrompt(options: AiFixOptions): string {
     ``,
     `Verify specifically:`,
     `- Every field assigned on \`JsFormatOptions\` in \`build_format_options\` still exists with that exact name (catch removed/renamed options).`,
-    `- Any formatter option newly added upstream is exposed through ALL layers: configuration.rs, resolve_config.rs, format_text.rs, and deployment/schema.json. A partial addition is a blocking issue.`,
+    `- Any formatter option newly added upstream is exposed through ALL layers: configuration.rs, resolve_config.rs, format_text.rs, schema.rs, and explain.rs, with deployment/schema.json and deployment/npm/config.d.ts regenerated rather than edited by hand. A partial addition is a blocking issue.`,
     `- Every config option added or renamed in this change has a spec test under \`tests/specs/\` exercising it (and any removed option's spec is deleted). A missing spec test is a blocking issue.`,
     `- Naming conventions are consistent (Rust snake_case fields, camelCase dprint keys, matching the schema).`,
     `- README.md was NOT modified (its documentation is maintained separately); flag any README.md change as a blocking issue.`,
@@ -0,0 +1,16 @@
+use dprint_core::plugins::process::get_parent_process_id_from_cli_args;
+use dprint_core::plugins::process::handle_process_stdio_messages;
+use dprint_core::plugins::process::start_parent_process_checker_task;
+use dprint_plugin_oxc::OxcProcessPluginHandler;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+  let runtime = tokio::runtime::Builder::new_multi_thread().enable_time().build()?;
+  runtime.block_on(async move {
+    if let Some(parent_process_id) = get_parent_process_id_from_cli_args() {
+      start_parent_process_checker_task(parent_process_id);
+    }
+
+    handle_process_stdio_messages(OxcProcessPluginHandler::default()).await
+  })?;
+  Ok(())
+}
@@ -1,18 +1,30 @@
 use dprint_core::configuration::ParseConfigurationError;
 use dprint_core::generate_str_to_from;
 use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum LineEnding {
   Lf,
   Cr,
   Crlf,
+  /// Uses the most common line ending in the file.
+  Auto,
+  /// Uses the line ending of the operating system.
+  System,
 }
 
-generate_str_to_from![LineEnding, [Lf, "lf"], [Cr, "cr"], [Crlf, "crlf"]];
+generate_str_to_from![
+  LineEnding,
+  [Lf, "lf"],
+  [Cr, "cr"],
+  [Crlf, "crlf"],
+  [Auto, "auto"],
+  [System, "system"]
+];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum IndentStyle {
   Tab,
@@ -21,7 +33,7 @@ pub enum IndentStyle {
 
 generate_str_to_from![IndentStyle, [Tab, "tab"], [Space, "space"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum Semicolons {
   Always,
@@ -30,7 +42,7 @@ pub enum Semicolons {
 
 generate_str_to_from![Semicolons, [Always, "always"], [AsNeeded, "asNeeded"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum QuoteStyle {
   Single,
@@ -39,7 +51,7 @@ pub enum QuoteStyle {
 
 generate_str_to_from![QuoteStyle, [Single, "single"], [Double, "double"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum QuoteProperties {
   AsNeeded,
@@ -54,7 +66,7 @@ generate_str_to_from![
   [Consistent, "consistent"]
 ];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ArrowParentheses {
   Always,
@@ -63,7 +75,7 @@ pub enum ArrowParentheses {
 
 generate_str_to_from![ArrowParentheses, [Always, "always"], [AsNeeded, "asNeeded"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum TrailingCommas {
   All,
@@ -73,7 +85,7 @@ pub enum TrailingCommas {
 
 generate_str_to_from![TrailingCommas, [All, "all"], [Es5, "es5"], [None, "none"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum AttributePosition {
   Auto,
@@ -82,7 +94,7 @@ pub enum AttributePosition {
 
 generate_str_to_from![AttributePosition, [Auto, "auto"], [Multiline, "multiline"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum Expand {
   Auto,
@@ -91,7 +103,7 @@ pub enum Expand {
 
 generate_str_to_from![Expand, [Auto, "auto"], [Never, "never"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum OperatorPosition {
   Start,
@@ -100,7 +112,16 @@ pub enum OperatorPosition {
 
 generate_str_to_from![OperatorPosition, [Start, "start"], [End, "end"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ByteOrderMark {
+  Preserve,
+  Remove,
+}
+
+generate_str_to_from![ByteOrderMark, [Preserve, "preserve"], [Remove, "remove"]];
+
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum SortOrder {
   Asc,
@@ -109,7 +130,36 @@ pub enum SortOrder {
 
 generate_str_to_from![SortOrder, [Asc, "asc"], [Desc, "desc"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NestingDepthExceeded {
+  Error,
+  Skip,
+}
+
+generate_str_to_from![NestingDepthExceeded, [Error, "error"], [Skip, "skip"]];
+
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VerifyIdempotency {
+  Off,
+  SecondPass,
+  Error,
+}
+
+generate_str_to_from![
+  VerifyIdempotency,
+  [Off, "off"],
+  [SecondPass, "secondPass"],
+  [Error, "error"]
+];
+
+/// Nesting depth allowed when `maxNestingDepth` isn't specified, which leaves
+/// plenty of room for handwritten code while fitting in the Wasm stack.
+pub(crate) const DEFAULT_MAX_NESTING_DEPTH: u32 = 500;
+pub(crate) const MAX_NESTING_DEPTH_RANGE: RangeInclusive<u32> = 1..=10_000;
+
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum CommentLineStrategy {
   SingleLine,
@@ -124,7 +174,7 @@ generate_str_to_from![
   [Keep, "keep"]
 ];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum LineWrappingStyle {
   Greedy,
@@ -133,7 +183,7 @@ pub enum LineWrappingStyle {
 
 generate_str_to_from![LineWrappingStyle, [Greedy, "greedy"], [Balance, "balance"]];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ImportSelector {
   Type,
@@ -166,7 +216,7 @@ generate_str_to_from![
   [Import, "import"]
 ];
 
-#[derive(Clone, PartialEq, Copy, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ImportModifier {
   SideEffect,
@@ -187,7 +237,61 @@ generate_str_to_from![
   [Named, "named"]
 ];
 
-#[derive(Default, Clone, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SourceLanguage {
+  Js,
+  Jsx,
+  Ts,
+  Tsx,
+  Dts,
+}
+
+generate_str_to_from![
+  SourceLanguage,
+  [Js, "js"],
+  [Jsx, "jsx"],
+  [Ts, "ts"],
+  [Tsx, "tsx"],
+  [Dts, "dts"]
+];
+
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ModuleKind {
+  Module,
+  Script,
+  Unambiguous,
+  CommonJs,
+}
+
+generate_str_to_from![
+  ModuleKind,
+  [Module, "module"],
+  [Script, "script"],
+  [Unambiguous, "unambiguous"],
+  [CommonJs, "commonjs"]
+];
+
+/// Which `.js` files contain JSX.
+#[derive(Clone, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JsxInJs {
+  Bool(bool),
+  /// Globs matched against the end of the file path (ex. `src/components/**`).
+  Globs(Vec<String>),
+}
+
+/// The kind of source a file name or extension is associated with.
+#[derive(Clone, PartialEq, Copy, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceTypeAssociation {
+  pub language: SourceLanguage,
+  /// Uses the module kind Oxc infers for the language when not set.
+  pub module_kind: Option<ModuleKind>,
+}
+
+#[derive(Default, Clone, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SortImportsOptions {
   #[serde(default)]
@@ -207,7 +311,7 @@ pub struct SortImportsOptions {
   pub custom_groups: Vec<crate::configuration::CustomGroupDefinition>,
 }
 
-#[derive(Default, Clone, Serialize, Deserialize)]
+#[derive(Default, Clone, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomGroupDefinition {
   #[serde(default)]
@@ -219,7 +323,7 @@ pub struct CustomGroupDefinition {
   pub modifiers: Vec<ImportModifier>,
 }
 
-#[derive(Default, Clone, Serialize, Deserialize)]
+#[derive(Default, Clone, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TailwindcssOptions {
   #[serde(default)]
@@ -230,7 +334,7 @@ pub struct TailwindcssOptions {
   pub preserve_whitespace: bool,
 }
 
-#[derive(Default, Clone, Serialize, Deserialize)]
+#[derive(Default, Clone, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JsdocOptions {
   #[serde(default = "default_true")]
@@ -259,7 +363,7 @@ const fn default_true() -> bool {
   true
 }
 
-#[derive(Default, Clone, Serialize, Deserialize)]
+#[derive(Default, Clone, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Configuration {
   pub line_ending: Option<LineEnding>,
@@ -282,4 +386,31 @@ pub struct Configuration {
   pub experimental_sort_imports: Option<SortImportsOptions>,
   pub experimental_tailwindcss: Option<TailwindcssOptions>,
   pub jsdoc: Option<JsdocOptions>,
+  pub byte_order_mark: Option<ByteOrderMark>,
+  /// Additional file extensions (lowercase and without a leading period).
+  #[serde(default)]
+  pub file_extensions: Vec<(String, SourceTypeAssociation)>,
+  #[serde(default)]
+  pub file_names: Vec<(String, SourceTypeAssociation)>,
+  /// Whether to detect the source type of files with an unknown extension
+  /// from their shebang (ex. `#!/usr/bin/env node`).
+  pub detect_shebang: Option<bool>,
+  /// How `.js` and `.jsx` files are parsed.
+  pub js_module_kind: Option<ModuleKind>,
+  pub jsx_in_js: Option<JsxInJs>,
+  pub allow_return_outside_function: Option<bool>,
+  pub allow_v8_intrinsics: Option<bool>,
+  /// How deeply syntax may be nested before the file isn't formatted, which
+  /// stops deeply nested (usually generated) code from overflowing the stack.
+  pub max_nesting_depth: Option<u32>,
+  pub nesting_depth_exceeded: Option<NestingDepthExceeded>,
+  /// Whether to format the output a second time to check that it's stable.
+  pub verify_idempotency: Option<VerifyIdempotency>,
+  /// Whether to check that the formatted output has the same syntax tree as
+  /// the input.
+  pub verify_semantics: Option<bool>,
+  /// Whether to check that formatting didn't lose or duplicate comments.
+  pub verify_comments: Option<bool>,
+  /// Whether the plugin caches the results of formatting.
+  pub cache: Option<bool>,
 }
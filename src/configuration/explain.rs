@@ -0,0 +1,614 @@
+use dprint_core::configuration::*;
+use oxc_formatter::JsFormatOptions;
+
+use super::ArrowParentheses;
+use super::AttributePosition;
+use super::ByteOrderMark;
+use super::CommentLineStrategy;
+use super::Configuration;
+use super::CustomGroupDefinition;
+use super::DEFAULT_MAX_NESTING_DEPTH;
+use super::Expand;
+use super::IndentStyle;
+use super::JsxInJs;
+use super::LineEnding;
+use super::LineWrappingStyle;
+use super::ModuleKind;
+use super::NestingDepthExceeded;
+use super::OperatorPosition;
+use super::QuoteProperties;
+use super::QuoteStyle;
+use super::Semicolons;
+use super::SortOrder;
+use super::SourceTypeAssociation;
+use super::TrailingCommas;
+use super::VerifyIdempotency;
+use super::resolve_config;
+
+/// Where the effective value of an option came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigValueSource {
+  /// Specified in the plugin's configuration.
+  PluginKey,
+  /// Specified in the plugin's configuration via a legacy alias (ex. `indentSize`).
+  Alias(&'static str),
+  /// Inherited from the specified key of dprint's global configuration.
+  GlobalConfig(&'static str),
+  /// Not specified, so the plugin's default is used.
+  PluginDefault,
+  /// Not specified, so Oxc's default is used.
+  OxcDefault,
+}
+
+impl std::fmt::Display for ConfigValueSource {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ConfigValueSource::PluginKey => write!(f, "plugin config"),
+      ConfigValueSource::Alias(alias) => write!(f, "plugin config via alias \"{}\"", alias),
+      ConfigValueSource::GlobalConfig(key) => write!(f, "global config \"{}\"", key),
+      ConfigValueSource::PluginDefault => write!(f, "plugin default"),
+      ConfigValueSource::OxcDefault => write!(f, "Oxc default"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplainedValue {
+  /// Property path (ex. `experimentalSortImports.order`).
+  pub name: String,
+  /// Effective value, formatted as it would be written in the configuration.
+  pub value: String,
+  pub source: ConfigValueSource,
+}
+
+/// The fully materialised options along with where each value came from.
+pub struct ExplainedConfiguration {
+  pub values: Vec<ExplainedValue>,
+  pub diagnostics: Vec<ConfigurationDiagnostic>,
+}
+
+impl ExplainedConfiguration {
+  pub fn get(&self, name: &str) -> Option<&ExplainedValue> {
+    self.values.iter().find(|value| value.name == name)
+  }
+}
+
+impl std::fmt::Display for ExplainedConfiguration {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for value in &self.values {
+      writeln!(f, "{} = {} ({})", value.name, value.value, value.source)?;
+    }
+    for diagnostic in &self.diagnostics {
+      writeln!(f, "error: {} ({})", diagnostic.message, diagnostic.property_name)?;
+    }
+    Ok(())
+  }
+}
+
+/// Resolves the configuration and explains the effective value of every
+/// option along with where it came from.
+///
+/// The plugin has no presets, so values come from either the plugin's
+/// configuration, dprint's global configuration, or a default.
+pub fn explain_config(config: ConfigKeyMap, global_config: &GlobalConfiguration) -> ExplainedConfiguration {
+  let specified = config;
+  let result = resolve_config(specified.clone(), global_config);
+  let resolved = &result.config;
+  let oxc_defaults = get_oxc_default_configuration();
+  let mut explainer = Explainer {
+    specified: &specified,
+    values: Vec::new(),
+  };
+
+  explainer.top_level(
+    "lineEnding",
+    &[],
+    Some("newLineKind"),
+    resolved.line_ending,
+    oxc_defaults.line_ending,
+  );
+  explainer.top_level(
+    "indentStyle",
+    &[],
+    Some("useTabs"),
+    resolved.indent_style,
+    oxc_defaults.indent_style,
+  );
+  explainer.top_level(
+    "indentWidth",
+    &["indentSize"],
+    Some("indentWidth"),
+    resolved.indent_width,
+    oxc_defaults.indent_width,
+  );
+  explainer.top_level(
+    "lineWidth",
+    &[],
+    Some("lineWidth"),
+    resolved.line_width,
+    oxc_defaults.line_width,
+  );
+  explainer.top_level("semicolons", &[], None, resolved.semicolons, oxc_defaults.semicolons);
+  explainer.top_level("quoteStyle", &[], None, resolved.quote_style, oxc_defaults.quote_style);
+  explainer.top_level(
+    "jsxQuoteStyle",
+    &[],
+    None,
+    resolved.jsx_quote_style,
+    oxc_defaults.jsx_quote_style,
+  );
+  explainer.top_level(
+    "quoteProperties",
+    &[],
+    None,
+    resolved.quote_properties,
+    oxc_defaults.quote_properties,
+  );
+  explainer.top_level(
+    "arrowParentheses",
+    &[],
+    None,
+    resolved.arrow_parentheses,
+    oxc_defaults.arrow_parentheses,
+  );
+  explainer.top_level(
+    "trailingCommas",
+    &["trailingComma"],
+    None,
+    resolved.trailing_commas,
+    oxc_defaults.trailing_commas,
+  );
+  explainer.top_level(
+    "bracketSpacing",
+    &[],
+    None,
+    resolved.bracket_spacing,
+    oxc_defaults.bracket_spacing,
+  );
+  explainer.top_level(
+    "bracketSameLine",
+    &[],
+    None,
+    resolved.bracket_same_line,
+    oxc_defaults.bracket_same_line,
+  );
+  explainer.top_level(
+    "attributePosition",
+    &[],
+    None,
+    resolved.attribute_position,
+    oxc_defaults.attribute_position,
+  );
+  explainer.top_level("expand", &[], None, resolved.expand, oxc_defaults.expand);
+  explainer.top_level(
+    "operatorPosition",
+    &[],
+    None,
+    resolved.operator_position,
+    oxc_defaults.operator_position,
+  );
+  explainer.top_level(
+    "experimentalTernaries",
+    &[],
+    None,
+    resolved.experimental_ternaries,
+    oxc_defaults.experimental_ternaries,
+  );
+  explainer.top_level(
+    "htmlWhitespaceSensitivityIgnore",
+    &[],
+    None,
+    resolved.html_whitespace_sensitivity_ignore,
+    oxc_defaults.html_whitespace_sensitivity_ignore,
+  );
+
+  match &resolved.experimental_sort_imports {
+    Some(options) => {
+      const PATH: &str = "experimentalSortImports";
+      explainer.nested(PATH, "partitionByNewline", options.partition_by_newline, false);
+      explainer.nested(PATH, "partitionByComment", options.partition_by_comment, false);
+      explainer.nested(PATH, "sortSideEffects", options.sort_side_effects, false);
+      explainer.nested_or_oxc_default(
+        PATH,
+        "order",
+        options.order,
+        sort_order_from_oxc(oxc_formatter::SortOrder::default()),
+      );
+      explainer.nested(PATH, "ignoreCase", options.ignore_case.unwrap_or(true), false);
+      explainer.nested(PATH, "newlinesBetween", options.newlines_between.unwrap_or(true), false);
+      explainer.nested(
+        PATH,
+        "internalPattern",
+        string_list_text(&options.internal_pattern),
+        false,
+      );
+      explainer.nested(
+        PATH,
+        "groups",
+        format!(
+          "[{}]",
+          options
+            .groups
+            .iter()
+            .map(|group| string_list_text(group.as_slice()))
+            .collect::<Vec<_>>()
+            .join(", ")
+        ),
+        false,
+      );
+      explainer.nested(PATH, "customGroups", custom_groups_text(&options.custom_groups), false);
+    }
+    None => explainer.disabled("experimentalSortImports"),
+  }
+
+  match &resolved.experimental_tailwindcss {
+    Some(options) => {
+      const PATH: &str = "experimentalTailwindcss";
+      explainer.nested(PATH, "functions", string_list_text(&options.functions), false);
+      explainer.nested(PATH, "attributes", string_list_text(&options.attributes), false);
+      explainer.nested(PATH, "preserveWhitespace", options.preserve_whitespace, false);
+    }
+    None => explainer.disabled("experimentalTailwindcss"),
+  }
+
+  match &resolved.jsdoc {
+    Some(options) => {
+      const PATH: &str = "jsdoc";
+      explainer.nested(PATH, "capitalizeDescriptions", options.capitalize_descriptions, false);
+      explainer.nested_or_oxc_default(
+        PATH,
+        "commentLineStrategy",
+        options.comment_line_strategy,
+        comment_line_strategy_from_oxc(oxc_formatter::CommentLineStrategy::default()),
+      );
+      explainer.nested(PATH, "separateTagGroups", options.separate_tag_groups, false);
+      explainer.nested(
+        PATH,
+        "separateReturnsFromParam",
+        options.separate_returns_from_param,
+        false,
+      );
+      explainer.nested(PATH, "bracketSpacing", options.bracket_spacing, false);
+      explainer.nested(PATH, "descriptionWithDot", options.description_with_dot, false);
+      explainer.nested(
+        PATH,
+        "addDefaultToDescription",
+        options.add_default_to_description,
+        false,
+      );
+      explainer.nested(PATH, "preferCodeFences", options.prefer_code_fences, false);
+      explainer.nested_or_oxc_default(
+        PATH,
+        "lineWrappingStyle",
+        options.line_wrapping_style,
+        line_wrapping_style_from_oxc(oxc_formatter::LineWrappingStyle::default()),
+      );
+      explainer.nested(PATH, "descriptionTag", options.description_tag, false);
+      explainer.nested(
+        PATH,
+        "keepUnparsableExampleIndent",
+        options.keep_unparsable_example_indent,
+        false,
+      );
+    }
+    None => explainer.disabled("jsdoc"),
+  }
+
+  explainer.plugin_option("byteOrderMark", resolved.byte_order_mark, ByteOrderMark::Preserve);
+  explainer.plugin_option(
+    "fileExtensions",
+    (!resolved.file_extensions.is_empty()).then(|| associations_text(&resolved.file_extensions)),
+    "{}".to_string(),
+  );
+  explainer.plugin_option(
+    "fileNames",
+    (!resolved.file_names.is_empty()).then(|| associations_text(&resolved.file_names)),
+    "{}".to_string(),
+  );
+  explainer.plugin_option("detectShebang", resolved.detect_shebang, false);
+  explainer.plugin_option("jsModuleKind", resolved.js_module_kind, ModuleKind::Unambiguous);
+  explainer.plugin_option(
+    "jsxInJs",
+    resolved.jsx_in_js.as_ref().map(|jsx_in_js| match jsx_in_js {
+      JsxInJs::Bool(value) => value.to_string(),
+      JsxInJs::Globs(globs) => string_list_text(globs),
+    }),
+    false.to_string(),
+  );
+  explainer.plugin_option(
+    "allowReturnOutsideFunction",
+    resolved.allow_return_outside_function,
+    false,
+  );
+  explainer.plugin_option("allowV8Intrinsics", resolved.allow_v8_intrinsics, false);
+  explainer.plugin_option("maxNestingDepth", resolved.max_nesting_depth, DEFAULT_MAX_NESTING_DEPTH);
+  explainer.plugin_option(
+    "nestingDepthExceeded",
+    resolved.nesting_depth_exceeded,
+    NestingDepthExceeded::Error,
+  );
+  explainer.plugin_option("verifyIdempotency", resolved.verify_idempotency, VerifyIdempotency::Off);
+  explainer.plugin_option("verifySemantics", resolved.verify_semantics, false);
+  explainer.plugin_option("verifyComments", resolved.verify_comments, false);
+  explainer.plugin_option("cache", resolved.cache, true);
+
+  ExplainedConfiguration {
+    values: explainer.values,
+    diagnostics: result.diagnostics,
+  }
+}
+
+struct Explainer<'a> {
+  specified: &'a ConfigKeyMap,
+  values: Vec<ExplainedValue>,
+}
+
+impl Explainer<'_> {
+  fn top_level<T: ToString>(
+    &mut self,
+    name: &'static str,
+    aliases: &[&'static str],
+    global_key: Option<&'static str>,
+    resolved: Option<T>,
+    oxc_default: Option<T>,
+  ) {
+    let (value, source) = match resolved {
+      Some(value) => {
+        let source = if is_specified(self.specified, name) {
+          ConfigValueSource::PluginKey
+        } else if let Some(alias) = aliases.iter().find(|alias| is_specified(self.specified, alias)) {
+          ConfigValueSource::Alias(*alias)
+        } else if let Some(global_key) = global_key {
+          ConfigValueSource::GlobalConfig(global_key)
+        } else {
+          ConfigValueSource::PluginKey
+        };
+        (value.to_string(), source)
+      }
+      None => (
+        oxc_default.map(|value| value.to_string()).unwrap_or_default(),
+        ConfigValueSource::OxcDefault,
+      ),
+    };
+    self.values.push(ExplainedValue {
+      name: name.to_string(),
+      value,
+      source,
+    });
+  }
+
+  /// Explains an option that is handled by the plugin rather than Oxc.
+  fn plugin_option<T: ToString>(&mut self, name: &'static str, resolved: Option<T>, default: T) {
+    let (value, source) = match resolved {
+      Some(value) => (value.to_string(), ConfigValueSource::PluginKey),
+      None => (default.to_string(), ConfigValueSource::PluginDefault),
+    };
+    self.values.push(ExplainedValue {
+      name: name.to_string(),
+      value,
+      source,
+    });
+  }
+
+  fn nested(&mut self, path: &str, name: &str, value: impl ToString, is_oxc_default: bool) {
+    let source = if self.is_nested_specified(path, name) {
+      ConfigValueSource::PluginKey
+    } else if is_oxc_default {
+      ConfigValueSource::OxcDefault
+    } else {
+      ConfigValueSource::PluginDefault
+    };
+    self.values.push(ExplainedValue {
+      name: format!("{}.{}", path, name),
+      value: value.to_string(),
+      source,
+    });
+  }
+
+  fn nested_or_oxc_default<T: ToString>(&mut self, path: &str, name: &str, value: Option<T>, oxc_default: T) {
+    let is_oxc_default = value.is_none();
+    self.nested(path, name, value.unwrap_or(oxc_default), is_oxc_default);
+  }
+
+  fn disabled(&mut self, name: &str) {
+    self.values.push(ExplainedValue {
+      name: name.to_string(),
+      value: "disabled".to_string(),
+      source: ConfigValueSource::OxcDefault,
+    });
+  }
+
+  fn is_nested_specified(&self, path: &str, name: &str) -> bool {
+    match self.specified.get(path) {
+      Some(ConfigKeyValue::Object(obj)) => is_specified(obj, name),
+      _ => false,
+    }
+  }
+}
+
+fn is_specified(obj: &ConfigKeyMap, name: &str) -> bool {
+  !matches!(obj.get(name), None | Some(ConfigKeyValue::Null))
+}
+
+fn string_list_text(values: &[String]) -> String {
+  format!(
+    "[{}]",
+    values
+      .iter()
+      .map(|value| format!("\"{}\"", value))
+      .collect::<Vec<_>>()
+      .join(", ")
+  )
+}
+
+fn custom_groups_text(groups: &[CustomGroupDefinition]) -> String {
+  let groups = groups
+    .iter()
+    .map(|group| {
+      let mut text = format!(
+        "{{ groupName: \"{}\", elementNamePattern: {}",
+        group.group_name,
+        string_list_text(&group.element_name_pattern)
+      );
+      if let Some(selector) = group.selector {
+        let selector = selector.to_string();
+        text.push_str(&format!(", selector: \"{}\"", selector));
+      }
+      if !group.modifiers.is_empty() {
+        let modifiers = group.modifiers.iter().map(|m| m.to_string()).collect::<Vec<_>>();
+        text.push_str(&format!(", modifiers: {}", string_list_text(&modifiers)));
+      }
+      text.push_str(" }");
+      text
+    })
+    .collect::<Vec<_>>();
+  format!("[{}]", groups.join(", "))
+}
+
+fn associations_text(associations: &[(String, SourceTypeAssociation)]) -> String {
+  let associations = associations
+    .iter()
+    .map(|(name, association)| {
+      let language = association.language.to_string();
+      match association.module_kind {
+        Some(module_kind) => {
+          let module_kind = module_kind.to_string();
+          format!(
+            "\"{}\": {{ language: \"{}\", moduleKind: \"{}\" }}",
+            name, language, module_kind
+          )
+        }
+        None => format!("\"{}\": \"{}\"", name, language),
+      }
+    })
+    .collect::<Vec<_>>();
+  format!("{{ {} }}", associations.join(", "))
+}
+
+/// Materialises Oxc's default options as plugin configuration values.
+pub fn get_oxc_default_configuration() -> Configuration {
+  let options = JsFormatOptions::default();
+  Configuration {
+    line_ending: Some(match options.line_ending {
+      oxc_formatter_core::LineEnding::Lf => LineEnding::Lf,
+      oxc_formatter_core::LineEnding::Cr => LineEnding::Cr,
+      oxc_formatter_core::LineEnding::Crlf => LineEnding::Crlf,
+    }),
+    indent_style: Some(match options.indent_style {
+      oxc_formatter_core::IndentStyle::Tab => IndentStyle::Tab,
+      oxc_formatter_core::IndentStyle::Space => IndentStyle::Space,
+    }),
+    indent_width: Some(options.indent_width.value()),
+    line_width: Some(options.line_width.value()),
+    semicolons: Some(match options.semicolons {
+      oxc_formatter::Semicolons::Always => Semicolons::Always,
+      oxc_formatter::Semicolons::AsNeeded => Semicolons::AsNeeded,
+    }),
+    quote_style: Some(quote_style_from_oxc(options.quote_style)),
+    jsx_quote_style: Some(quote_style_from_oxc(options.jsx_quote_style)),
+    quote_properties: Some(match options.quote_properties {
+      oxc_formatter::QuoteProperties::AsNeeded => QuoteProperties::AsNeeded,
+      oxc_formatter::QuoteProperties::Preserve => QuoteProperties::Preserve,
+      oxc_formatter::QuoteProperties::Consistent => QuoteProperties::Consistent,
+    }),
+    arrow_parentheses: Some(match options.arrow_parentheses {
+      oxc_formatter::ArrowParentheses::Always => ArrowParentheses::Always,
+      oxc_formatter::ArrowParentheses::AsNeeded => ArrowParentheses::AsNeeded,
+    }),
+    trailing_commas: Some(match options.trailing_commas {
+      oxc_formatter::TrailingCommas::All => TrailingCommas::All,
+      oxc_formatter::TrailingCommas::Es5 => TrailingCommas::Es5,
+      oxc_formatter::TrailingCommas::None => TrailingCommas::None,
+    }),
+    bracket_spacing: Some(options.bracket_spacing.value()),
+    bracket_same_line: Some(options.bracket_same_line.value()),
+    attribute_position: Some(match options.attribute_position {
+      oxc_formatter::AttributePosition::Auto => AttributePosition::Auto,
+      oxc_formatter::AttributePosition::Multiline => AttributePosition::Multiline,
+    }),
+    expand: Some(match options.expand {
+      oxc_formatter::Expand::Auto => Expand::Auto,
+      oxc_formatter::Expand::Never => Expand::Never,
+    }),
+    operator_position: Some(match options.operator_position {
+      oxc_formatter::OperatorPosition::Start => OperatorPosition::Start,
+      oxc_formatter::OperatorPosition::End => OperatorPosition::End,
+    }),
+    experimental_ternaries: Some(options.experimental_ternaries),
+    html_whitespace_sensitivity_ignore: Some(options.html_whitespace_sensitivity_ignore),
+    // the remaining options are disabled or handled by the plugin
+    ..Default::default()
+  }
+}
+
+fn quote_style_from_oxc(quote_style: oxc_formatter::QuoteStyle) -> QuoteStyle {
+  match quote_style {
+    oxc_formatter::QuoteStyle::Single => QuoteStyle::Single,
+    oxc_formatter::QuoteStyle::Double => QuoteStyle::Double,
+  }
+}
+
+fn sort_order_from_oxc(order: oxc_formatter::SortOrder) -> SortOrder {
+  match order {
+    oxc_formatter::SortOrder::Asc => SortOrder::Asc,
+    oxc_formatter::SortOrder::Desc => SortOrder::Desc,
+  }
+}
+
+fn comment_line_strategy_from_oxc(strategy: oxc_formatter::CommentLineStrategy) -> CommentLineStrategy {
+  match strategy {
+    oxc_formatter::CommentLineStrategy::SingleLine => CommentLineStrategy::SingleLine,
+    oxc_formatter::CommentLineStrategy::Multiline => CommentLineStrategy::Multiline,
+    oxc_formatter::CommentLineStrategy::Keep => CommentLineStrategy::Keep,
+  }
+}
+
+fn line_wrapping_style_from_oxc(style: oxc_formatter::LineWrappingStyle) -> LineWrappingStyle {
+  match style {
+    oxc_formatter::LineWrappingStyle::Greedy => LineWrappingStyle::Greedy,
+    oxc_formatter::LineWrappingStyle::Balance => LineWrappingStyle::Balance,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn explains_value_sources() {
+    let config: ConfigKeyMap = serde_json::from_value(serde_json::json!({
+      "quoteStyle": "single",
+      "indentSize": 4,
+      "jsdoc": { "bracketSpacing": true },
+    }))
+    .unwrap();
+    let global_config = GlobalConfiguration {
+      line_width: Some(100),
+      ..Default::default()
+    };
+    let explained = explain_config(config, &global_config);
+
+    let get = |name: &str| {
+      let value = explained.get(name).unwrap();
+      (value.value.as_str(), value.source)
+    };
+    assert_eq!(get("quoteStyle"), ("single", ConfigValueSource::PluginKey));
+    assert_eq!(get("indentWidth"), ("4", ConfigValueSource::Alias("indentSize")));
+    assert_eq!(get("lineWidth"), ("100", ConfigValueSource::GlobalConfig("lineWidth")));
+    assert_eq!(get("semicolons"), ("always", ConfigValueSource::OxcDefault));
+    assert_eq!(
+      get("experimentalSortImports"),
+      ("disabled", ConfigValueSource::OxcDefault)
+    );
+    assert_eq!(get("jsdoc.bracketSpacing"), ("true", ConfigValueSource::PluginKey));
+    assert_eq!(
+      get("jsdoc.descriptionWithDot"),
+      ("false", ConfigValueSource::PluginDefault)
+    );
+    assert_eq!(
+      get("jsdoc.commentLineStrategy"),
+      ("singleLine", ConfigValueSource::OxcDefault)
+    );
+    assert!(explained.diagnostics.is_empty());
+  }
+}
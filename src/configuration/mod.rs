@@ -1,6 +1,11 @@
 #[allow(clippy::module_inception)]
 mod configuration;
+mod explain;
 mod resolve_config;
+mod schema;
+mod validation;
 
 pub use configuration::*;
+pub use explain::*;
 pub use resolve_config::*;
+pub use schema::*;
@@ -2,14 +2,26 @@ use super::CommentLineStrategy;
 use super::Configuration;
 use super::CustomGroupDefinition;
 use super::ImportModifier;
+use super::ImportSelector;
 use super::IndentStyle;
 use super::JsdocOptions;
+use super::JsxInJs;
 use super::LineEnding;
 use super::LineWrappingStyle;
+use super::MAX_NESTING_DEPTH_RANGE;
+use super::ModuleKind;
 use super::SortImportsOptions;
 use super::SortOrder;
+use super::SourceLanguage;
+use super::SourceTypeAssociation;
 use super::TailwindcssOptions;
+use super::validation::validate_glob;
+use super::validation::validate_group_entry;
+use super::validation::validate_non_empty;
 use dprint_core::configuration::*;
+use oxc_formatter_core::IndentWidth;
+use oxc_formatter_core::LineWidth;
+use std::ops::RangeInclusive;
 
 /// Resolves configuration from a collection of key value strings.
 ///
@@ -45,21 +57,31 @@ pub fn resolve_config(
       false => IndentStyle::Space,
     },
   ));
-  let indent_width = get_nullable_value(&mut config, "indentWidth", &mut diagnostics)
-    .or_else(|| get_nullable_value(&mut config, "indentSize", &mut diagnostics))
-    .or(global_config.indent_width);
-  let line_width = get_nullable_value(&mut config, "lineWidth", &mut diagnostics).or(
-    global_config
-      .line_width
-      .map(|l| std::cmp::min(u16::MAX as u32, l) as u16),
-  );
+  let indent_width = check_range(
+    get_nullable_value::<u32>(&mut config, "indentWidth", &mut diagnostics)
+      .or_else(|| get_nullable_value(&mut config, "indentSize", &mut diagnostics))
+      .or(global_config.indent_width.map(u32::from)),
+    "indentWidth",
+    IndentWidth::MIN as u32..=IndentWidth::MAX as u32,
+    &mut diagnostics,
+  )
+  .map(|value| value as u8);
+  let line_width = check_range(
+    get_nullable_value::<u32>(&mut config, "lineWidth", &mut diagnostics).or(global_config.line_width),
+    "lineWidth",
+    LineWidth::MIN as u32..=LineWidth::MAX as u32,
+    &mut diagnostics,
+  )
+  .map(|value| value as u16);
 
   let resolved_config = Configuration {
     line_ending: get_nullable_value(&mut config, "lineEnding", &mut diagnostics).or(
       match global_config.new_line_kind {
+        Some(NewLineKind::Auto) => Some(LineEnding::Auto),
         Some(NewLineKind::CarriageReturnLineFeed) => Some(LineEnding::Crlf),
         Some(NewLineKind::LineFeed) => Some(LineEnding::Lf),
-        _ => None,
+        Some(NewLineKind::System) => Some(LineEnding::System),
+        None => None,
       },
     ),
     indent_style,
@@ -86,8 +108,36 @@ pub fn resolve_config(
     experimental_sort_imports: resolve_sort_imports_options(&mut config, &mut diagnostics),
     experimental_tailwindcss: resolve_tailwindcss_options(&mut config, &mut diagnostics),
     jsdoc: resolve_jsdoc_options(&mut config, &mut diagnostics),
+    byte_order_mark: get_nullable_value(&mut config, "byteOrderMark", &mut diagnostics),
+    file_extensions: resolve_source_type_associations(&mut config, "fileExtensions", true, &mut diagnostics),
+    file_names: resolve_source_type_associations(&mut config, "fileNames", false, &mut diagnostics),
+    detect_shebang: get_nullable_value(&mut config, "detectShebang", &mut diagnostics),
+    js_module_kind: get_nullable_value(&mut config, "jsModuleKind", &mut diagnostics),
+    jsx_in_js: resolve_jsx_in_js(&mut config, &mut diagnostics),
+    allow_return_outside_function: get_nullable_value(&mut config, "allowReturnOutsideFunction", &mut diagnostics),
+    allow_v8_intrinsics: get_nullable_value(&mut config, "allowV8Intrinsics", &mut diagnostics),
+    max_nesting_depth: check_range(
+      get_nullable_value(&mut config, "maxNestingDepth", &mut diagnostics),
+      "maxNestingDepth",
+      MAX_NESTING_DEPTH_RANGE,
+      &mut diagnostics,
+    ),
+    nesting_depth_exceeded: get_nullable_value(&mut config, "nestingDepthExceeded", &mut diagnostics),
+    verify_idempotency: get_nullable_value(&mut config, "verifyIdempotency", &mut diagnostics),
+    verify_semantics: get_nullable_value(&mut config, "verifySemantics", &mut diagnostics),
+    verify_comments: get_nullable_value(&mut config, "verifyComments", &mut diagnostics),
+    cache: get_nullable_value(&mut config, "cache", &mut diagnostics),
   };
 
+  // parenthesized expression nodes aren't supported by Oxc's formatter
+  if get_nullable_value::<bool>(&mut config, "preserveParens", &mut diagnostics) == Some(true) {
+    diagnostics.push(ConfigurationDiagnostic {
+      property_name: "preserveParens".to_string(),
+      message: "Only false is supported because Oxc's formatter needs parenthesized expressions removed while parsing."
+        .to_string(),
+    });
+  }
+
   diagnostics.extend(get_unknown_property_diagnostics(config));
 
   ResolveConfigurationResult {
@@ -96,53 +146,116 @@ pub fn resolve_config(
   }
 }
 
+/// Reports a diagnostic and discards the value when it's outside the range
+/// supported by Oxc.
+fn check_range(
+  value: Option<u32>,
+  property_name: &str,
+  range: RangeInclusive<u32>,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Option<u32> {
+  let value = value?;
+  if range.contains(&value) {
+    Some(value)
+  } else {
+    diagnostics.push(ConfigurationDiagnostic {
+      property_name: property_name.to_string(),
+      message: format!(
+        "Expected a value between {} and {}, but found {}.",
+        range.start(),
+        range.end(),
+        value
+      ),
+    });
+    None
+  }
+}
+
 fn resolve_sort_imports_options(
   config: &mut ConfigKeyMap,
   diagnostics: &mut Vec<ConfigurationDiagnostic>,
 ) -> Option<SortImportsOptions> {
-  let value = config.shift_remove("experimentalSortImports")?;
-
-  let obj = match value.into_object() {
-    Some(obj) => obj,
-    None => {
-      diagnostics.push(ConfigurationDiagnostic {
-        property_name: "experimentalSortImports".to_string(),
-        message: "expected an object".to_string(),
-      });
-      return None;
-    }
-  };
-
-  let mut obj = obj;
-  let mut inner_diagnostics = Vec::new();
+  const PATH: &str = "experimentalSortImports";
+  let mut obj = take_object(config, PATH, PATH, diagnostics)?;
 
   let partition_by_newline =
-    get_nullable_value::<bool>(&mut obj, "partitionByNewline", &mut inner_diagnostics).unwrap_or(false);
+    get_nested_value::<bool>(&mut obj, "partitionByNewline", PATH, diagnostics).unwrap_or(false);
   let partition_by_comment =
-    get_nullable_value::<bool>(&mut obj, "partitionByComment", &mut inner_diagnostics).unwrap_or(false);
-  let sort_side_effects =
-    get_nullable_value::<bool>(&mut obj, "sortSideEffects", &mut inner_diagnostics).unwrap_or(false);
-  let order = get_nullable_value::<SortOrder>(&mut obj, "order", &mut inner_diagnostics);
-  let ignore_case = get_nullable_value::<bool>(&mut obj, "ignoreCase", &mut inner_diagnostics);
-  let newlines_between = get_nullable_value::<bool>(&mut obj, "newlinesBetween", &mut inner_diagnostics);
-
-  // Parse internalPattern as array of strings
-  let internal_pattern = obj
-    .shift_remove("internalPattern")
-    .and_then(|v| v.into_array())
-    .map(|arr| arr.into_iter().filter_map(|v| v.into_string()).collect::<Vec<_>>())
+    get_nested_value::<bool>(&mut obj, "partitionByComment", PATH, diagnostics).unwrap_or(false);
+  let sort_side_effects = get_nested_value::<bool>(&mut obj, "sortSideEffects", PATH, diagnostics).unwrap_or(false);
+  let order = get_nested_value::<SortOrder>(&mut obj, "order", PATH, diagnostics);
+  let ignore_case = get_nested_value::<bool>(&mut obj, "ignoreCase", PATH, diagnostics);
+  let newlines_between = get_nested_value::<bool>(&mut obj, "newlinesBetween", PATH, diagnostics);
+  let internal_pattern = take_string_array(
+    &mut obj,
+    "internalPattern",
+    &format!("{PATH}.internalPattern"),
+    validate_non_empty,
+    diagnostics,
+  )
+  .unwrap_or_default();
+
+  let custom_groups = take_array(&mut obj, "customGroups", &format!("{PATH}.customGroups"), diagnostics)
+    .map(|arr| {
+      let mut custom_groups = Vec::with_capacity(arr.len());
+      for (i, value) in arr.into_iter().enumerate() {
+        let path = format!("{PATH}.customGroups[{i}]");
+        let Some(custom_group) = resolve_custom_group(value, &path, diagnostics) else {
+          continue;
+        };
+        if !custom_group.group_name.is_empty()
+          && custom_groups
+            .iter()
+            .any(|g: &CustomGroupDefinition| g.group_name == custom_group.group_name)
+        {
+          diagnostics.push(ConfigurationDiagnostic {
+            property_name: format!("{path}.groupName"),
+            message: format!("Duplicate group name '{}'.", custom_group.group_name),
+          });
+          continue;
+        }
+        custom_groups.push(custom_group);
+      }
+      custom_groups
+    })
     .unwrap_or_default();
+  let custom_group_names = custom_groups
+    .iter()
+    .map(|g| g.group_name.as_str())
+    .filter(|name| !name.is_empty())
+    .collect::<Vec<_>>();
 
   // Parse groups as array of arrays of strings
-  let groups = obj
-    .shift_remove("groups")
-    .and_then(|v| v.into_array())
+  let mut seen_group_entries: Vec<String> = Vec::new();
+  let groups = take_array(&mut obj, "groups", &format!("{PATH}.groups"), diagnostics)
     .map(|arr| {
       arr
         .into_iter()
-        .filter_map(|v| {
-          v.into_array()
-            .map(|inner| inner.into_iter().filter_map(|s| s.into_string()).collect::<Vec<_>>())
+        .enumerate()
+        .filter_map(|(i, value)| {
+          let path = format!("{PATH}.groups[{i}]");
+          match value {
+            ConfigKeyValue::Array(inner) => Some(string_array_items(
+              inner,
+              &path,
+              |entry| {
+                validate_group_entry(entry, &custom_group_names)?;
+                if seen_group_entries.iter().any(|seen| seen == entry) {
+                  return Err(format!("Group '{}' is used more than once.", entry));
+                }
+                seen_group_entries.push(entry.to_string());
+                Ok(())
+              },
+              diagnostics,
+            )),
+            _ => {
+              diagnostics.push(ConfigurationDiagnostic {
+                property_name: path,
+                message: "expected an array of strings".to_string(),
+              });
+              None
+            }
+          }
         })
         .collect::<Vec<_>>()
     })
@@ -157,56 +270,7 @@ fn resolve_sort_imports_options(
       ]
     });
 
-  // Parse customGroups as array of objects with groupName and elementNamePattern
-  let custom_groups = obj
-    .shift_remove("customGroups")
-    .and_then(|v| v.into_array())
-    .map(|arr| {
-      arr
-        .into_iter()
-        .filter_map(|v| {
-          let mut obj = v.into_object()?;
-          let group_name = obj
-            .shift_remove("groupName")
-            .and_then(|v| v.into_string())
-            .unwrap_or_default();
-          let element_name_pattern = obj
-            .shift_remove("elementNamePattern")
-            .and_then(|v| v.into_array())
-            .map(|arr| arr.into_iter().filter_map(|v| v.into_string()).collect::<Vec<_>>())
-            .unwrap_or_default();
-          let selector = get_nullable_value(&mut obj, "selector", &mut inner_diagnostics);
-          let modifiers = obj
-            .shift_remove("modifiers")
-            .and_then(|v| v.into_array())
-            .map(|arr| {
-              arr
-                .into_iter()
-                .filter_map(|v| v.into_string())
-                .filter_map(|value| value.parse::<ImportModifier>().ok())
-                .collect()
-            })
-            .unwrap_or_default();
-          Some(CustomGroupDefinition {
-            group_name,
-            element_name_pattern,
-            selector,
-            modifiers,
-          })
-        })
-        .collect::<Vec<_>>()
-    })
-    .unwrap_or_default();
-
-  // Report unknown properties within experimentalSortImports
-  for (key, _) in obj {
-    inner_diagnostics.push(ConfigurationDiagnostic {
-      property_name: format!("experimentalSortImports.{}", key),
-      message: "Unknown property".to_string(),
-    });
-  }
-
-  diagnostics.extend(inner_diagnostics);
+  diagnostics.extend(get_nested_unknown_property_diagnostics(obj, PATH));
 
   Some(SortImportsOptions {
     partition_by_newline,
@@ -221,52 +285,115 @@ fn resolve_sort_imports_options(
   })
 }
 
-fn resolve_tailwindcss_options(
-  config: &mut ConfigKeyMap,
+fn resolve_custom_group(
+  value: ConfigKeyValue,
+  path: &str,
   diagnostics: &mut Vec<ConfigurationDiagnostic>,
-) -> Option<TailwindcssOptions> {
-  let value = config.shift_remove("experimentalTailwindcss")?;
-
-  let obj = match value.into_object() {
-    Some(obj) => obj,
-    None => {
+) -> Option<CustomGroupDefinition> {
+  let mut obj = match value {
+    ConfigKeyValue::Object(obj) => obj,
+    _ => {
       diagnostics.push(ConfigurationDiagnostic {
-        property_name: "experimentalTailwindcss".to_string(),
+        property_name: path.to_string(),
         message: "expected an object".to_string(),
       });
       return None;
     }
   };
 
-  let mut obj = obj;
-  let mut inner_diagnostics = Vec::new();
+  let group_name = match obj.shift_remove("groupName") {
+    Some(ConfigKeyValue::String(group_name)) if !group_name.is_empty() => group_name,
+    Some(ConfigKeyValue::String(_)) => {
+      diagnostics.push(ConfigurationDiagnostic {
+        property_name: format!("{path}.groupName"),
+        message: "expected a non-empty string".to_string(),
+      });
+      String::new()
+    }
+    Some(_) => {
+      diagnostics.push(ConfigurationDiagnostic {
+        property_name: format!("{path}.groupName"),
+        message: "expected a string".to_string(),
+      });
+      String::new()
+    }
+    None => {
+      diagnostics.push(ConfigurationDiagnostic {
+        property_name: format!("{path}.groupName"),
+        message: "missing required property".to_string(),
+      });
+      String::new()
+    }
+  };
+  let element_name_pattern = take_string_array(
+    &mut obj,
+    "elementNamePattern",
+    &format!("{path}.elementNamePattern"),
+    validate_glob,
+    diagnostics,
+  )
+  .unwrap_or_default();
+  let selector = get_nested_value::<ImportSelector>(&mut obj, "selector", path, diagnostics);
+  let modifiers = take_array(&mut obj, "modifiers", &format!("{path}.modifiers"), diagnostics)
+    .map(|arr| {
+      arr
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, value)| {
+          let property_name = format!("{path}.modifiers[{i}]");
+          let result = match value {
+            ConfigKeyValue::String(value) => value.parse::<ImportModifier>().map_err(|err| err.to_string()),
+            _ => Err("expected a string".to_string()),
+          };
+          match result {
+            Ok(modifier) => Some(modifier),
+            Err(message) => {
+              diagnostics.push(ConfigurationDiagnostic { property_name, message });
+              None
+            }
+          }
+        })
+        .collect()
+    })
+    .unwrap_or_default();
 
-  let preserve_whitespace =
-    get_nullable_value::<bool>(&mut obj, "preserveWhitespace", &mut inner_diagnostics).unwrap_or(false);
+  diagnostics.extend(get_nested_unknown_property_diagnostics(obj, path));
 
-  // Parse functions as array of strings
-  let functions = obj
-    .shift_remove("functions")
-    .and_then(|v| v.into_array())
-    .map(|arr| arr.into_iter().filter_map(|v| v.into_string()).collect::<Vec<_>>())
-    .unwrap_or_default();
+  Some(CustomGroupDefinition {
+    group_name,
+    element_name_pattern,
+    selector,
+    modifiers,
+  })
+}
 
-  // Parse attributes as array of strings
-  let attributes = obj
-    .shift_remove("attributes")
-    .and_then(|v| v.into_array())
-    .map(|arr| arr.into_iter().filter_map(|v| v.into_string()).collect::<Vec<_>>())
-    .unwrap_or_default();
+fn resolve_tailwindcss_options(
+  config: &mut ConfigKeyMap,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Option<TailwindcssOptions> {
+  const PATH: &str = "experimentalTailwindcss";
+  let mut obj = take_object(config, PATH, PATH, diagnostics)?;
 
-  // Report unknown properties within experimentalTailwindcss
-  for (key, _) in obj {
-    inner_diagnostics.push(ConfigurationDiagnostic {
-      property_name: format!("experimentalTailwindcss.{}", key),
-      message: "Unknown property".to_string(),
-    });
-  }
+  let preserve_whitespace =
+    get_nested_value::<bool>(&mut obj, "preserveWhitespace", PATH, diagnostics).unwrap_or(false);
+  let functions = take_string_array(
+    &mut obj,
+    "functions",
+    &format!("{PATH}.functions"),
+    |_| Ok(()),
+    diagnostics,
+  )
+  .unwrap_or_default();
+  let attributes = take_string_array(
+    &mut obj,
+    "attributes",
+    &format!("{PATH}.attributes"),
+    |_| Ok(()),
+    diagnostics,
+  )
+  .unwrap_or_default();
 
-  diagnostics.extend(inner_diagnostics);
+  diagnostics.extend(get_nested_unknown_property_diagnostics(obj, PATH));
 
   Some(TailwindcssOptions {
     functions,
@@ -279,45 +406,416 @@ fn resolve_jsdoc_options(
   config: &mut ConfigKeyMap,
   diagnostics: &mut Vec<ConfigurationDiagnostic>,
 ) -> Option<JsdocOptions> {
-  let value = config.shift_remove("jsdoc")?;
-  let mut obj = match value.into_object() {
-    Some(obj) => obj,
-    None => {
-      diagnostics.push(ConfigurationDiagnostic {
-        property_name: "jsdoc".to_string(),
-        message: "expected an object".to_string(),
-      });
-      return None;
-    }
-  };
-  let mut inner_diagnostics = Vec::new();
+  const PATH: &str = "jsdoc";
+  let mut obj = take_object(config, PATH, PATH, diagnostics)?;
   let options = JsdocOptions {
-    capitalize_descriptions: get_nullable_value(&mut obj, "capitalizeDescriptions", &mut inner_diagnostics)
-      .unwrap_or(true),
-    comment_line_strategy: get_nullable_value::<CommentLineStrategy>(
-      &mut obj,
-      "commentLineStrategy",
-      &mut inner_diagnostics,
-    ),
-    separate_tag_groups: get_nullable_value(&mut obj, "separateTagGroups", &mut inner_diagnostics).unwrap_or(false),
-    separate_returns_from_param: get_nullable_value(&mut obj, "separateReturnsFromParam", &mut inner_diagnostics)
+    capitalize_descriptions: get_nested_value(&mut obj, "capitalizeDescriptions", PATH, diagnostics).unwrap_or(true),
+    comment_line_strategy: get_nested_value::<CommentLineStrategy>(&mut obj, "commentLineStrategy", PATH, diagnostics),
+    separate_tag_groups: get_nested_value(&mut obj, "separateTagGroups", PATH, diagnostics).unwrap_or(false),
+    separate_returns_from_param: get_nested_value(&mut obj, "separateReturnsFromParam", PATH, diagnostics)
       .unwrap_or(false),
-    bracket_spacing: get_nullable_value(&mut obj, "bracketSpacing", &mut inner_diagnostics).unwrap_or(false),
-    description_with_dot: get_nullable_value(&mut obj, "descriptionWithDot", &mut inner_diagnostics).unwrap_or(false),
-    add_default_to_description: get_nullable_value(&mut obj, "addDefaultToDescription", &mut inner_diagnostics)
+    bracket_spacing: get_nested_value(&mut obj, "bracketSpacing", PATH, diagnostics).unwrap_or(false),
+    description_with_dot: get_nested_value(&mut obj, "descriptionWithDot", PATH, diagnostics).unwrap_or(false),
+    add_default_to_description: get_nested_value(&mut obj, "addDefaultToDescription", PATH, diagnostics)
       .unwrap_or(true),
-    prefer_code_fences: get_nullable_value(&mut obj, "preferCodeFences", &mut inner_diagnostics).unwrap_or(false),
-    line_wrapping_style: get_nullable_value::<LineWrappingStyle>(&mut obj, "lineWrappingStyle", &mut inner_diagnostics),
-    description_tag: get_nullable_value(&mut obj, "descriptionTag", &mut inner_diagnostics).unwrap_or(false),
-    keep_unparsable_example_indent: get_nullable_value(&mut obj, "keepUnparsableExampleIndent", &mut inner_diagnostics)
+    prefer_code_fences: get_nested_value(&mut obj, "preferCodeFences", PATH, diagnostics).unwrap_or(false),
+    line_wrapping_style: get_nested_value::<LineWrappingStyle>(&mut obj, "lineWrappingStyle", PATH, diagnostics),
+    description_tag: get_nested_value(&mut obj, "descriptionTag", PATH, diagnostics).unwrap_or(false),
+    keep_unparsable_example_indent: get_nested_value(&mut obj, "keepUnparsableExampleIndent", PATH, diagnostics)
       .unwrap_or(false),
   };
-  for (key, _) in obj {
-    inner_diagnostics.push(ConfigurationDiagnostic {
-      property_name: format!("jsdoc.{}", key),
+  diagnostics.extend(get_nested_unknown_property_diagnostics(obj, PATH));
+  Some(options)
+}
+
+fn resolve_jsx_in_js(config: &mut ConfigKeyMap, diagnostics: &mut Vec<ConfigurationDiagnostic>) -> Option<JsxInJs> {
+  const KEY: &str = "jsxInJs";
+  match config.shift_remove(KEY)? {
+    ConfigKeyValue::Bool(value) => Some(JsxInJs::Bool(value)),
+    ConfigKeyValue::Array(arr) => Some(JsxInJs::Globs(string_array_items(arr, KEY, validate_glob, diagnostics))),
+    ConfigKeyValue::Null => None,
+    _ => {
+      diagnostics.push(ConfigurationDiagnostic {
+        property_name: KEY.to_string(),
+        message: "expected a boolean or an array of globs".to_string(),
+      });
+      None
+    }
+  }
+}
+
+fn resolve_source_type_associations(
+  config: &mut ConfigKeyMap,
+  key: &str,
+  is_extension: bool,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Vec<(String, SourceTypeAssociation)> {
+  let Some(obj) = take_object(config, key, key, diagnostics) else {
+    return Vec::new();
+  };
+
+  let mut associations = Vec::with_capacity(obj.len());
+  for (name, value) in obj {
+    let path = format!("{}.{}", key, name);
+    let name = if is_extension {
+      name.trim_start_matches('.').to_lowercase()
+    } else {
+      name
+    };
+    if name.is_empty() {
+      diagnostics.push(ConfigurationDiagnostic {
+        property_name: path,
+        message: "expected a non-empty name".to_string(),
+      });
+      continue;
+    }
+    if let Some(association) = resolve_source_type_association(value, &path, diagnostics) {
+      associations.push((name, association));
+    }
+  }
+  associations
+}
+
+/// Resolves either a language (ex. `"js"`) or an object with a `language`
+/// and `moduleKind`.
+fn resolve_source_type_association(
+  value: ConfigKeyValue,
+  path: &str,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Option<SourceTypeAssociation> {
+  match value {
+    ConfigKeyValue::String(language) => match language.parse::<SourceLanguage>() {
+      Ok(language) => Some(SourceTypeAssociation {
+        language,
+        module_kind: None,
+      }),
+      Err(err) => {
+        diagnostics.push(ConfigurationDiagnostic {
+          property_name: path.to_string(),
+          message: err.to_string(),
+        });
+        None
+      }
+    },
+    ConfigKeyValue::Object(mut obj) => {
+      let has_language = obj.contains_key("language");
+      let language = get_nested_value::<SourceLanguage>(&mut obj, "language", path, diagnostics);
+      let module_kind = get_nested_value::<ModuleKind>(&mut obj, "moduleKind", path, diagnostics);
+      diagnostics.extend(get_nested_unknown_property_diagnostics(obj, path));
+      if language.is_none() && !has_language {
+        diagnostics.push(ConfigurationDiagnostic {
+          property_name: format!("{}.language", path),
+          message: "missing required property".to_string(),
+        });
+      }
+      Some(SourceTypeAssociation {
+        language: language?,
+        module_kind,
+      })
+    }
+    _ => {
+      diagnostics.push(ConfigurationDiagnostic {
+        property_name: path.to_string(),
+        message: "expected a string or an object".to_string(),
+      });
+      None
+    }
+  }
+}
+
+/// Removes an object value from the map, reporting a diagnostic at `path`
+/// when the value is not an object.
+fn take_object(
+  obj: &mut ConfigKeyMap,
+  key: &str,
+  path: &str,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Option<ConfigKeyMap> {
+  match obj.shift_remove(key)? {
+    ConfigKeyValue::Object(obj) => Some(obj),
+    ConfigKeyValue::Null => None,
+    _ => {
+      diagnostics.push(ConfigurationDiagnostic {
+        property_name: path.to_string(),
+        message: "expected an object".to_string(),
+      });
+      None
+    }
+  }
+}
+
+/// Removes an array value from the map, reporting a diagnostic at `path`
+/// when the value is not an array.
+fn take_array(
+  obj: &mut ConfigKeyMap,
+  key: &str,
+  path: &str,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Option<Vec<ConfigKeyValue>> {
+  match obj.shift_remove(key)? {
+    ConfigKeyValue::Array(arr) => Some(arr),
+    ConfigKeyValue::Null => None,
+    _ => {
+      diagnostics.push(ConfigurationDiagnostic {
+        property_name: path.to_string(),
+        message: "expected an array".to_string(),
+      });
+      None
+    }
+  }
+}
+
+fn take_string_array(
+  obj: &mut ConfigKeyMap,
+  key: &str,
+  path: &str,
+  validate: impl FnMut(&str) -> Result<(), String>,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Option<Vec<String>> {
+  take_array(obj, key, path, diagnostics).map(|arr| string_array_items(arr, path, validate, diagnostics))
+}
+
+/// Collects the strings of an array, reporting a diagnostic for each item
+/// that is not a string or that fails validation.
+fn string_array_items(
+  arr: Vec<ConfigKeyValue>,
+  path: &str,
+  mut validate: impl FnMut(&str) -> Result<(), String>,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Vec<String> {
+  arr
+    .into_iter()
+    .enumerate()
+    .filter_map(|(i, value)| {
+      let result = match value {
+        ConfigKeyValue::String(value) => validate(&value).map(|_| value),
+        _ => Err("expected a string".to_string()),
+      };
+      match result {
+        Ok(value) => Some(value),
+        Err(message) => {
+          diagnostics.push(ConfigurationDiagnostic {
+            property_name: format!("{path}[{i}]"),
+            message,
+          });
+          None
+        }
+      }
+    })
+    .collect()
+}
+
+/// Gets a value from a nested object, prefixing any diagnostic with the
+/// path of the object.
+fn get_nested_value<T>(
+  obj: &mut ConfigKeyMap,
+  key: &str,
+  parent_path: &str,
+  diagnostics: &mut Vec<ConfigurationDiagnostic>,
+) -> Option<T>
+where
+  T: std::str::FromStr,
+  <T as std::str::FromStr>::Err: std::fmt::Display,
+{
+  let mut inner_diagnostics = Vec::new();
+  let value = get_nullable_value(obj, key, &mut inner_diagnostics);
+  diagnostics.extend(inner_diagnostics.into_iter().map(|diagnostic| ConfigurationDiagnostic {
+    property_name: format!("{}.{}", parent_path, diagnostic.property_name),
+    message: diagnostic.message,
+  }));
+  value
+}
+
+fn get_nested_unknown_property_diagnostics(obj: ConfigKeyMap, parent_path: &str) -> Vec<ConfigurationDiagnostic> {
+  obj
+    .into_iter()
+    .map(|(key, _)| ConfigurationDiagnostic {
+      property_name: format!("{}.{}", parent_path, key),
       message: "Unknown property".to_string(),
-    });
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn resolve_diagnostics(value: serde_json::Value) -> Vec<(String, String)> {
+    let config: ConfigKeyMap = serde_json::from_value(value).unwrap();
+    resolve_config(config, &GlobalConfiguration::default())
+      .diagnostics
+      .into_iter()
+      .map(|d| (d.property_name, d.message))
+      .collect()
+  }
+
+  #[test]
+  fn reports_invalid_sort_imports_values_with_paths() {
+    let diagnostics = resolve_diagnostics(serde_json::json!({
+      "experimentalSortImports": {
+        "order": "sideways",
+        "internalPattern": ["~/", 5],
+        "groups": [["builtin"], "external", [true]],
+        "customGroups": [
+          { "groupName": "react", "elementNamePattern": ["react"] },
+          "react",
+          { "groupName": "styles", "modifiers": ["type", "unknown"], "other": 1 }
+        ]
+      }
+    }));
+    let paths = diagnostics.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+      paths,
+      vec![
+        "experimentalSortImports.order",
+        "experimentalSortImports.internalPattern[1]",
+        "experimentalSortImports.customGroups[1]",
+        "experimentalSortImports.customGroups[2].modifiers[1]",
+        "experimentalSortImports.customGroups[2].other",
+        "experimentalSortImports.groups[1]",
+        "experimentalSortImports.groups[2][0]",
+      ]
+    );
+  }
+
+  #[test]
+  fn reports_unknown_groups_and_invalid_patterns() {
+    let diagnostics = resolve_diagnostics(serde_json::json!({
+      "experimentalSortImports": {
+        "internalPattern": ["~/", "[abc", ""],
+        "groups": [["react", "type-builtin"], ["externals"], ["builtin", "react"], ["unknown"]],
+        "customGroups": [
+          { "groupName": "react", "elementNamePattern": ["react", "react-{dom"] },
+          { "groupName": "react", "elementNamePattern": ["preact"] }
+        ]
+      }
+    }));
+    let paths = diagnostics.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+      paths,
+      vec![
+        "experimentalSortImports.internalPattern[2]",
+        "experimentalSortImports.customGroups[0].elementNamePattern[1]",
+        "experimentalSortImports.customGroups[1].groupName",
+        "experimentalSortImports.groups[1][0]",
+        "experimentalSortImports.groups[2][1]",
+      ]
+    );
+  }
+
+  #[test]
+  fn reports_out_of_range_numbers() {
+    let diagnostics = resolve_diagnostics(serde_json::json!({
+      "indentWidth": 100,
+      "lineWidth": 0,
+    }));
+    assert_eq!(
+      diagnostics,
+      vec![
+        (
+          "indentWidth".to_string(),
+          format!(
+            "Expected a value between {} and {}, but found 100.",
+            IndentWidth::MIN,
+            IndentWidth::MAX
+          )
+        ),
+        (
+          "lineWidth".to_string(),
+          format!(
+            "Expected a value between {} and {}, but found 0.",
+            LineWidth::MIN,
+            LineWidth::MAX
+          )
+        ),
+      ]
+    );
+
+    let config = resolve_config(
+      ConfigKeyMap::new(),
+      &GlobalConfiguration {
+        line_width: Some(100_000),
+        ..Default::default()
+      },
+    );
+    assert_eq!(config.config.line_width, None);
+    assert_eq!(config.diagnostics.len(), 1);
+  }
+
+  #[test]
+  fn reports_unsupported_preserve_parens() {
+    assert!(resolve_diagnostics(serde_json::json!({ "preserveParens": false })).is_empty());
+    let diagnostics = resolve_diagnostics(serde_json::json!({ "preserveParens": true }));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].0, "preserveParens");
+  }
+
+  #[test]
+  fn reports_invalid_tailwindcss_values_with_paths() {
+    let diagnostics = resolve_diagnostics(serde_json::json!({
+      "experimentalTailwindcss": {
+        "functions": "clsx",
+        "attributes": ["class", 1],
+      }
+    }));
+    assert_eq!(
+      diagnostics,
+      vec![
+        (
+          "experimentalTailwindcss.functions".to_string(),
+          "expected an array".to_string()
+        ),
+        (
+          "experimentalTailwindcss.attributes[1]".to_string(),
+          "expected a string".to_string()
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn resolves_source_type_associations() {
+    let config: ConfigKeyMap = serde_json::from_value(serde_json::json!({
+      "fileExtensions": {
+        ".ES6": "js",
+        "pac": { "language": "js", "moduleKind": "script" },
+        "d.mts": "dts",
+        "gs": "coffee",
+        "": "js",
+        "jsm": { "moduleKind": "module", "other": true },
+      },
+      "fileNames": { "Jakefile": "js" },
+    }))
+    .unwrap();
+    let result = resolve_config(config, &GlobalConfiguration::default());
+    let extensions = result
+      .config
+      .file_extensions
+      .iter()
+      .map(|(extension, _)| extension.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(extensions, vec!["es6", "pac", "d.mts"]);
+    assert!(
+      result.config.file_extensions[1].1
+        == SourceTypeAssociation {
+          language: SourceLanguage::Js,
+          module_kind: Some(ModuleKind::Script),
+        }
+    );
+    assert_eq!(result.config.file_names[0].0, "Jakefile");
+    let paths = result
+      .diagnostics
+      .iter()
+      .map(|d| d.property_name.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      paths,
+      vec![
+        "fileExtensions.gs",
+        "fileExtensions.",
+        "fileExtensions.jsm.other",
+        "fileExtensions.jsm.language",
+      ]
+    );
   }
-  diagnostics.extend(inner_diagnostics);
-  Some(options)
 }
@@ -0,0 +1,889 @@
+use oxc_formatter_core::IndentWidth;
+use oxc_formatter_core::LineWidth;
+use serde::Serialize;
+use serde::Serializer;
+use serde::ser::SerializeMap;
+
+use super::ArrowParentheses;
+use super::AttributePosition;
+use super::ByteOrderMark;
+use super::CommentLineStrategy;
+use super::DEFAULT_MAX_NESTING_DEPTH;
+use super::Expand;
+use super::ImportModifier;
+use super::ImportSelector;
+use super::IndentStyle;
+use super::LineEnding;
+use super::LineWrappingStyle;
+use super::MAX_NESTING_DEPTH_RANGE;
+use super::ModuleKind;
+use super::NestingDepthExceeded;
+use super::OperatorPosition;
+use super::QuoteProperties;
+use super::QuoteStyle;
+use super::Semicolons;
+use super::SortOrder;
+use super::SourceLanguage;
+use super::TrailingCommas;
+use super::VerifyIdempotency;
+
+/// Describes a configuration property.
+pub struct PropertyDescription {
+  pub name: &'static str,
+  pub description: &'static str,
+  pub kind: PropertyKind,
+  pub default: Option<DefaultValue>,
+}
+
+pub enum PropertyKind {
+  Boolean,
+  Number {
+    minimum: u32,
+    maximum: u32,
+  },
+  String,
+  Enum(Vec<EnumValueDescription>),
+  Array(Box<PropertyKind>),
+  Object(Vec<PropertyDescription>),
+  /// An object with arbitrary keys whose values are all of the same kind.
+  Map(Box<PropertyKind>),
+  /// A value that may be any of the kinds.
+  Union(Vec<PropertyKind>),
+}
+
+pub struct EnumValueDescription {
+  pub value: String,
+  pub description: Option<&'static str>,
+}
+
+#[derive(Clone, Serialize)]
+#[serde(untagged)]
+pub enum DefaultValue {
+  Bool(bool),
+  Number(u32),
+  String(String),
+  Array(Vec<DefaultValue>),
+}
+
+/// Gets a description of all the configuration properties understood by
+/// `resolve_config`.
+pub fn get_property_descriptions() -> Vec<PropertyDescription> {
+  vec![
+    property(
+      "lineEnding",
+      "The kind of line ending.",
+      enum_kind(&[
+        (LineEnding::Lf, "Line feed."),
+        (LineEnding::Crlf, "Carriage return, line feed."),
+        (LineEnding::Cr, "Carriage return."),
+        (
+          LineEnding::Auto,
+          "For each file, uses the line ending that is most common in the file.",
+        ),
+        (
+          LineEnding::System,
+          "Uses carriage return, line feed when the process plugin runs on Windows and line feed otherwise. The Wasm plugin can't tell which OS it runs on, so it always uses line feed; use `auto` to keep each file's line endings instead.",
+        ),
+      ]),
+      Some(enum_default(LineEnding::Lf)),
+    ),
+    property(
+      "indentWidth",
+      "The number of characters for an indent.",
+      PropertyKind::Number {
+        minimum: IndentWidth::MIN as u32,
+        maximum: IndentWidth::MAX as u32,
+      },
+      Some(DefaultValue::Number(2)),
+    ),
+    property(
+      "indentStyle",
+      "Whether to use tabs or spaces.",
+      enum_kind(&[(IndentStyle::Tab, "Use tabs."), (IndentStyle::Space, "Use spaces.")]),
+      Some(enum_default(IndentStyle::Space)),
+    ),
+    property(
+      "lineWidth",
+      "How many characters can be written on a single line.",
+      PropertyKind::Number {
+        minimum: LineWidth::MIN as u32,
+        maximum: LineWidth::MAX as u32,
+      },
+      Some(DefaultValue::Number(80)),
+    ),
+    property(
+      "semicolons",
+      "Whether to use semicolons.",
+      enum_kind(&[
+        (
+          Semicolons::Always,
+          "Semicolons are always added at the end of each statement.",
+        ),
+        (
+          Semicolons::AsNeeded,
+          "Semicolons are added only in places where it's needed, to protect from ASI.",
+        ),
+      ]),
+      Some(enum_default(Semicolons::Always)),
+    ),
+    property(
+      "quoteStyle",
+      "The type of quote used when representing string literals.",
+      quote_style_kind(),
+      Some(enum_default(QuoteStyle::Double)),
+    ),
+    property(
+      "jsxQuoteStyle",
+      "The type of quote used in JSX attributes.",
+      quote_style_kind(),
+      Some(enum_default(QuoteStyle::Double)),
+    ),
+    property(
+      "quoteProperties",
+      "Whether property names inside objects should be quoted.",
+      enum_kind(&[
+        (QuoteProperties::AsNeeded, "Quotes when necessary."),
+        (QuoteProperties::Preserve, "Maintains quotes on properties."),
+        (
+          QuoteProperties::Consistent,
+          "All properties are quoted if any property requires quoting.",
+        ),
+      ]),
+      Some(enum_default(QuoteProperties::AsNeeded)),
+    ),
+    property(
+      "arrowParentheses",
+      "Whether to add non-necessary parentheses to arrow functions.",
+      enum_kind(&[
+        (ArrowParentheses::Always, "Parentheses are always added."),
+        (ArrowParentheses::AsNeeded, "Parentheses are added only when necessary."),
+      ]),
+      Some(enum_default(ArrowParentheses::Always)),
+    ),
+    property(
+      "trailingCommas",
+      "Print trailing commas wherever possible in multi-line comma-separated syntactic structures.",
+      enum_kind(&[
+        (TrailingCommas::All, "Trailing commas are always added."),
+        (
+          TrailingCommas::Es5,
+          "Trailing commas are added only in places where it's supported in ES5.",
+        ),
+        (TrailingCommas::None, "Trailing commas are never added."),
+      ]),
+      Some(enum_default(TrailingCommas::All)),
+    ),
+    property(
+      "bracketSpacing",
+      "Surround the inner contents of some braces with spaces.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(true)),
+    ),
+    property(
+      "bracketSameLine",
+      "Place the last angle bracket in JSX tags on the same line as the last attribute.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "attributePosition",
+      "How JSX/TSX attributes are positioned.",
+      enum_kind(&[
+        (AttributePosition::Auto, "Attributes are automatically positioned."),
+        (
+          AttributePosition::Multiline,
+          "Each attribute is placed on its own line.",
+        ),
+      ]),
+      Some(enum_default(AttributePosition::Auto)),
+    ),
+    property(
+      "expand",
+      "Whether to expand object and array literals to multiple lines.",
+      enum_kind(&[
+        (
+          Expand::Auto,
+          "Objects are expanded when the first property has a leading newline. Arrays are always collapsed unless they contain a newline.",
+        ),
+        (
+          Expand::Never,
+          "Objects and arrays are never expanded, if they are shorter than the line width.",
+        ),
+      ]),
+      Some(enum_default(Expand::Auto)),
+    ),
+    property(
+      "operatorPosition",
+      "Controls the position of operators in binary expressions when they wrap lines.",
+      enum_kind(&[
+        (
+          OperatorPosition::Start,
+          "Places the operator at the beginning of the next line.",
+        ),
+        (
+          OperatorPosition::End,
+          "Places the operator at the end of the current line.",
+        ),
+      ]),
+      Some(enum_default(OperatorPosition::End)),
+    ),
+    property(
+      "experimentalTernaries",
+      "[EXPERIMENTAL - NOT FULLY SUPPORTED] Try prettier's new ternary formatting before it becomes the default behavior.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "htmlWhitespaceSensitivityIgnore",
+      "Whether HTML-in-JS templates always use hard line breaks for wrapping.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "experimentalSortImports",
+      "[EXPERIMENTAL] Sort import statements. When set, enables import sorting with the specified options.",
+      PropertyKind::Object(sort_imports_properties()),
+      None,
+    ),
+    property(
+      "experimentalTailwindcss",
+      "[EXPERIMENTAL] Enable Tailwind CSS class sorting. When set, enables class sorting with the specified options.",
+      PropertyKind::Object(vec![
+        property(
+          "functions",
+          "Custom functions to sort classes in.",
+          string_array_kind(),
+          Some(DefaultValue::Array(Vec::new())),
+        ),
+        property(
+          "attributes",
+          "Custom attributes to sort classes in.",
+          string_array_kind(),
+          Some(DefaultValue::Array(Vec::new())),
+        ),
+        property(
+          "preserveWhitespace",
+          "Preserve whitespace in class strings.",
+          PropertyKind::Boolean,
+          Some(DefaultValue::Bool(false)),
+        ),
+      ]),
+      None,
+    ),
+    property(
+      "jsdoc",
+      "Enable JSDoc comment formatting with the specified options.",
+      PropertyKind::Object(jsdoc_properties()),
+      None,
+    ),
+    property(
+      "byteOrderMark",
+      "What to do with the byte order mark of UTF-8 files. UTF-16 files always keep theirs.",
+      enum_kind(&[
+        (
+          ByteOrderMark::Preserve,
+          "Keep the byte order mark when the file has one.",
+        ),
+        (ByteOrderMark::Remove, "Remove the byte order mark."),
+      ]),
+      Some(enum_default(ByteOrderMark::Preserve)),
+    ),
+    property(
+      "fileExtensions",
+      "Additional file extensions to format, mapped to the kind of source they contain (ex. `{ \"es6\": \"js\", \"d.mts\": \"dts\" }`). When several extensions match a file, the longest one is used.",
+      source_type_associations_kind(),
+      None,
+    ),
+    property(
+      "fileNames",
+      "Exact file names to format, mapped to the kind of source they contain (ex. `{ \"Jakefile\": \"js\" }`). These take precedence over file extensions.",
+      source_type_associations_kind(),
+      None,
+    ),
+    property(
+      "detectShebang",
+      "Whether to format files with an unknown extension (ex. executables in a `bin` directory) based on their shebang. Node scripts are formatted as JavaScript and Deno, ts-node, tsx, and Bun scripts as TypeScript.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "jsModuleKind",
+      "How `.js` and `.jsx` files are parsed. Files with a `.mjs` or `.cjs` extension are always parsed as ES or CommonJS modules.",
+      module_kind_kind(),
+      Some(enum_default(ModuleKind::Unambiguous)),
+    ),
+    property(
+      "jsxInJs",
+      "Whether to parse `.js` files as JavaScript with JSX, or globs of the `.js` files to parse with JSX (ex. `[\"src/components/**\"]`). dprint doesn't tell plugins where the configuration file is, so globs that don't start with `/` or `**` match the end of a file's path (ex. `src/*.js` matches `src/App.js` in any directory). Files that fail to parse with JSX are parsed again without it.",
+      PropertyKind::Union(vec![PropertyKind::Boolean, string_array_kind()]),
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "allowReturnOutsideFunction",
+      "Whether to allow `return` statements outside of functions (ex. in CommonJS modules or build tool snippets).",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "allowV8Intrinsics",
+      "Whether to allow V8 intrinsics such as `%DebugPrint(foo)`.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "preserveParens",
+      "Not supported. Oxc's formatter needs parenthesized expressions removed while parsing, so only `false` is accepted and `true` reports a diagnostic. Parentheses that are needed are still printed.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "maxNestingDepth",
+      "How deeply brackets and syntax nodes may be nested (ex. in generated arrays, JSX, or long chains of binary expressions) before the file is not formatted, which prevents the formatter from running out of stack space.",
+      PropertyKind::Number {
+        minimum: *MAX_NESTING_DEPTH_RANGE.start(),
+        maximum: *MAX_NESTING_DEPTH_RANGE.end(),
+      },
+      Some(DefaultValue::Number(DEFAULT_MAX_NESTING_DEPTH)),
+    ),
+    property(
+      "nestingDepthExceeded",
+      "What to do with files nested deeper than `maxNestingDepth`.",
+      enum_kind(&[
+        (NestingDepthExceeded::Error, "Report an error for the file."),
+        (NestingDepthExceeded::Skip, "Leave the file unformatted."),
+      ]),
+      Some(enum_default(NestingDepthExceeded::Error)),
+    ),
+    property(
+      "verifyIdempotency",
+      "Whether to format the output a second time to check that formatting is stable, since Oxc occasionally produces output that changes when formatted again.",
+      enum_kind(&[
+        (VerifyIdempotency::Off, "Don't format the output again."),
+        (
+          VerifyIdempotency::SecondPass,
+          "Use the result of the second pass when it differs from the first.",
+        ),
+        (
+          VerifyIdempotency::Error,
+          "Report an error with the lines that changed when the second pass differs from the first.",
+        ),
+      ]),
+      Some(enum_default(VerifyIdempotency::Off)),
+    ),
+    property(
+      "verifySemantics",
+      "Whether to parse the formatted output and check that its syntax tree matches the input's, ignoring parentheses, trivia, and intended changes such as sorted imports. The file is not formatted when they differ, which guards against formatter bugs that change the meaning of the code.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "verifyComments",
+      "Whether to check that the formatted output has the same comments as the input, so lost or duplicated comments are reported as an error instead of written. Only the number of JSDoc comments is checked when `jsdoc` is enabled since they're reformatted.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "cache",
+      "Whether the plugin caches formatted files in memory so files that are sent again without changes (ex. by editors) are not formatted again. The configurations share a cache of up to 16 MB.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(true)),
+    ),
+  ]
+}
+
+fn source_type_associations_kind() -> PropertyKind {
+  let language_kind = || {
+    enum_kind(&[
+      (SourceLanguage::Js, "JavaScript."),
+      (SourceLanguage::Jsx, "JavaScript with JSX."),
+      (SourceLanguage::Ts, "TypeScript."),
+      (SourceLanguage::Tsx, "TypeScript with JSX."),
+      (SourceLanguage::Dts, "TypeScript declarations."),
+    ])
+  };
+  PropertyKind::Map(Box::new(PropertyKind::Union(vec![
+    language_kind(),
+    PropertyKind::Object(vec![
+      property("language", "The language of the source.", language_kind(), None),
+      property(
+        "moduleKind",
+        "How the source is parsed. Defaults to detecting whether the file is a module.",
+        module_kind_kind(),
+        None,
+      ),
+    ]),
+  ])))
+}
+
+fn module_kind_kind() -> PropertyKind {
+  enum_kind(&[
+    (ModuleKind::Module, "An ES module."),
+    (ModuleKind::Script, "A classic script."),
+    (
+      ModuleKind::Unambiguous,
+      "A module when it has imports or exports, otherwise a script.",
+    ),
+    (ModuleKind::CommonJs, "A CommonJS module."),
+  ])
+}
+
+fn sort_imports_properties() -> Vec<PropertyDescription> {
+  vec![
+    property(
+      "partitionByNewline",
+      "Partition imports by newlines.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "partitionByComment",
+      "Partition imports by comments.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "sortSideEffects",
+      "Sort side effects imports.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "order",
+      "Sort order (ascending or descending).",
+      enum_kind(&[
+        (SortOrder::Asc, "Sort in ascending order (A-Z)."),
+        (SortOrder::Desc, "Sort in descending order (Z-A)."),
+      ]),
+      Some(enum_default(SortOrder::Asc)),
+    ),
+    property(
+      "ignoreCase",
+      "Ignore case when sorting.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(true)),
+    ),
+    property(
+      "newlinesBetween",
+      "Whether to insert blank lines between different import groups.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(true)),
+    ),
+    property(
+      "internalPattern",
+      "Patterns to identify internal imports.",
+      string_array_kind(),
+      Some(DefaultValue::Array(Vec::new())),
+    ),
+    property(
+      "groups",
+      "Custom grouping of imports. Each inner array represents a group and each entry is a selector optionally prefixed by modifiers (ex. `type-external`), `unknown`, or the name of a custom group.",
+      PropertyKind::Array(Box::new(string_array_kind())),
+      Some(DefaultValue::Array(
+        [
+          &["builtin"][..],
+          &["external"],
+          &["internal", "subpath"],
+          &["parent", "sibling", "index"],
+          &["style"],
+          &["unknown"],
+        ]
+        .iter()
+        .map(|group| DefaultValue::Array(group.iter().map(|s| DefaultValue::String(s.to_string())).collect()))
+        .collect(),
+      )),
+    ),
+    property(
+      "customGroups",
+      "Definitions for custom import groups referenced by groups.",
+      PropertyKind::Array(Box::new(PropertyKind::Object(vec![
+        property("groupName", "The custom group name.", PropertyKind::String, None),
+        property(
+          "elementNamePattern",
+          "Glob patterns that match import sources.",
+          string_array_kind(),
+          Some(DefaultValue::Array(Vec::new())),
+        ),
+        property(
+          "selector",
+          "The import selector that must match.",
+          enum_kind_without_descriptions(&[
+            ImportSelector::Type,
+            ImportSelector::SideEffectStyle,
+            ImportSelector::SideEffect,
+            ImportSelector::Style,
+            ImportSelector::Index,
+            ImportSelector::Sibling,
+            ImportSelector::Parent,
+            ImportSelector::Subpath,
+            ImportSelector::Internal,
+            ImportSelector::Builtin,
+            ImportSelector::External,
+            ImportSelector::Import,
+          ]),
+          None,
+        ),
+        property(
+          "modifiers",
+          "Import modifiers that must all be present.",
+          PropertyKind::Array(Box::new(enum_kind_without_descriptions(&[
+            ImportModifier::SideEffect,
+            ImportModifier::Type,
+            ImportModifier::Value,
+            ImportModifier::Default,
+            ImportModifier::Wildcard,
+            ImportModifier::Named,
+          ]))),
+          Some(DefaultValue::Array(Vec::new())),
+        ),
+      ]))),
+      Some(DefaultValue::Array(Vec::new())),
+    ),
+  ]
+}
+
+fn jsdoc_properties() -> Vec<PropertyDescription> {
+  vec![
+    property(
+      "capitalizeDescriptions",
+      "Capitalize the first letter of tag descriptions.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(true)),
+    ),
+    property(
+      "commentLineStrategy",
+      "How to format JSDoc comment blocks.",
+      enum_kind(&[
+        (
+          CommentLineStrategy::SingleLine,
+          "Convert to a single line when possible.",
+        ),
+        (CommentLineStrategy::Multiline, "Always use multiple lines."),
+        (CommentLineStrategy::Keep, "Preserve the original line layout."),
+      ]),
+      Some(enum_default(CommentLineStrategy::SingleLine)),
+    ),
+    property(
+      "separateTagGroups",
+      "Add blank lines between different tag groups.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "separateReturnsFromParam",
+      "Add a blank line between the last @param and @returns.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "bracketSpacing",
+      "Add spaces inside JSDoc type braces.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "descriptionWithDot",
+      "Add a trailing dot to descriptions.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "addDefaultToDescription",
+      "Append default values to @param descriptions.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(true)),
+    ),
+    property(
+      "preferCodeFences",
+      "Use fenced code blocks for code without a language tag.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "lineWrappingStyle",
+      "How JSDoc description lines wrap at the configured line width.",
+      enum_kind(&[
+        (LineWrappingStyle::Greedy, "Always re-wrap text to fit the line width."),
+        (LineWrappingStyle::Balance, "Keep original line breaks when they fit."),
+      ]),
+      Some(enum_default(LineWrappingStyle::Greedy)),
+    ),
+    property(
+      "descriptionTag",
+      "Emit an @description tag instead of an inline description.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+    property(
+      "keepUnparsableExampleIndent",
+      "Preserve indentation in unparsable @example code.",
+      PropertyKind::Boolean,
+      Some(DefaultValue::Bool(false)),
+    ),
+  ]
+}
+
+fn property(
+  name: &'static str,
+  description: &'static str,
+  kind: PropertyKind,
+  default: Option<DefaultValue>,
+) -> PropertyDescription {
+  PropertyDescription {
+    name,
+    description,
+    kind,
+    default,
+  }
+}
+
+fn quote_style_kind() -> PropertyKind {
+  enum_kind(&[
+    (QuoteStyle::Double, "Use double quotes."),
+    (QuoteStyle::Single, "Use single quotes."),
+  ])
+}
+
+fn string_array_kind() -> PropertyKind {
+  PropertyKind::Array(Box::new(PropertyKind::String))
+}
+
+fn enum_kind<T: ToString>(values: &[(T, &'static str)]) -> PropertyKind {
+  PropertyKind::Enum(
+    values
+      .iter()
+      .map(|(value, description)| EnumValueDescription {
+        value: value.to_string(),
+        description: Some(description),
+      })
+      .collect(),
+  )
+}
+
+fn enum_kind_without_descriptions<T: ToString>(values: &[T]) -> PropertyKind {
+  PropertyKind::Enum(
+    values
+      .iter()
+      .map(|value| EnumValueDescription {
+        value: value.to_string(),
+        description: None,
+      })
+      .collect(),
+  )
+}
+
+fn enum_default<T: ToString>(value: T) -> DefaultValue {
+  DefaultValue::String(value.to_string())
+}
+
+/// JSON schema of the plugin's configuration.
+#[derive(Serialize)]
+pub struct JsonSchema {
+  #[serde(rename = "$schema")]
+  schema: &'static str,
+  #[serde(rename = "$id")]
+  id: &'static str,
+  #[serde(flatten)]
+  root: SchemaNode,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SchemaNode {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  description: Option<&'static str>,
+  #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+  kind: Option<&'static str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  default: Option<DefaultValue>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  minimum: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  maximum: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  one_of: Option<Vec<SchemaEnumValue>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  items: Option<Box<SchemaNode>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  properties: Option<SchemaProperties>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  additional_properties: Option<Box<SchemaNode>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  any_of: Option<Vec<SchemaNode>>,
+}
+
+#[derive(Serialize)]
+struct SchemaEnumValue {
+  #[serde(rename = "const")]
+  value: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  description: Option<&'static str>,
+}
+
+struct SchemaProperties(Vec<(&'static str, SchemaNode)>);
+
+impl Serialize for SchemaProperties {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(self.0.len()))?;
+    for (name, node) in &self.0 {
+      map.serialize_entry(name, node)?;
+    }
+    map.end()
+  }
+}
+
+/// Generates the JSON schema that is published alongside the plugin
+/// (`deployment/schema.json`).
+pub fn generate_json_schema() -> JsonSchema {
+  JsonSchema {
+    schema: "http://json-schema.org/draft-07/schema#",
+    id: "https://plugins.dprint.dev/dprint/dprint-plugin-oxc/0.0.0/schema.json",
+    root: kind_to_schema_node(PropertyKind::Object(get_property_descriptions()), None, None),
+  }
+}
+
+fn kind_to_schema_node(
+  kind: PropertyKind,
+  description: Option<&'static str>,
+  default: Option<DefaultValue>,
+) -> SchemaNode {
+  let mut node = SchemaNode {
+    description,
+    kind: Some("object"),
+    default,
+    minimum: None,
+    maximum: None,
+    one_of: None,
+    items: None,
+    properties: None,
+    additional_properties: None,
+    any_of: None,
+  };
+  match kind {
+    PropertyKind::Boolean => node.kind = Some("boolean"),
+    PropertyKind::Number { minimum, maximum } => {
+      node.kind = Some("number");
+      node.minimum = Some(minimum);
+      node.maximum = Some(maximum);
+    }
+    PropertyKind::String => node.kind = Some("string"),
+    PropertyKind::Enum(values) => {
+      node.kind = Some("string");
+      node.one_of = Some(
+        values
+          .into_iter()
+          .map(|value| SchemaEnumValue {
+            value: value.value,
+            description: value.description,
+          })
+          .collect(),
+      );
+    }
+    PropertyKind::Array(items) => {
+      node.kind = Some("array");
+      node.items = Some(Box::new(kind_to_schema_node(*items, None, None)));
+    }
+    PropertyKind::Object(properties) => {
+      node.properties = Some(SchemaProperties(
+        properties
+          .into_iter()
+          .map(|property| {
+            (
+              property.name,
+              kind_to_schema_node(property.kind, Some(property.description), property.default),
+            )
+          })
+          .collect(),
+      ));
+    }
+    PropertyKind::Map(values) => {
+      node.additional_properties = Some(Box::new(kind_to_schema_node(*values, None, None)));
+    }
+    PropertyKind::Union(kinds) => {
+      node.kind = None;
+      node.any_of = Some(
+        kinds
+          .into_iter()
+          .map(|kind| kind_to_schema_node(kind, None, None))
+          .collect(),
+      );
+    }
+  }
+  node
+}
+
+/// Generates the TypeScript declarations of the configuration that ship in
+/// the npm package (`deployment/npm/config.d.ts`).
+pub fn generate_typescript_declarations() -> String {
+  let mut text = String::new();
+  text.push_str("// This file is generated from the plugin's configuration types. Do not edit it by hand.\n");
+  text.push_str("// Run `UPDATE_SCHEMA=1 cargo test --test schema` to regenerate it.\n\n");
+  text.push_str("/** Configuration for the `\"oxc\"` key of a dprint configuration file. */\n");
+  text.push_str("export interface Configuration {\n");
+  write_typescript_properties(&mut text, &get_property_descriptions(), 1);
+  text.push_str("}\n");
+  text
+}
+
+fn write_typescript_properties(text: &mut String, properties: &[PropertyDescription], indent_level: usize) {
+  let indent = "  ".repeat(indent_level);
+  for property in properties {
+    text.push_str(&format!("{indent}/**\n{indent} * {}\n", property.description));
+    if let Some(default) = &property.default {
+      text.push_str(&format!(
+        "{indent} *\n{indent} * @default {}\n",
+        default_value_text(default)
+      ));
+    }
+    text.push_str(&format!("{indent} */\n"));
+    text.push_str(&format!(
+      "{indent}{}?: {};\n",
+      property.name,
+      typescript_type_text(&property.kind, indent_level)
+    ));
+  }
+}
+
+fn typescript_type_text(kind: &PropertyKind, indent_level: usize) -> String {
+  match kind {
+    PropertyKind::Boolean => "boolean".to_string(),
+    PropertyKind::Number { .. } => "number".to_string(),
+    PropertyKind::String => "string".to_string(),
+    PropertyKind::Enum(values) => values
+      .iter()
+      .map(|value| format!("\"{}\"", value.value))
+      .collect::<Vec<_>>()
+      .join(" | "),
+    PropertyKind::Array(items) => {
+      let items_text = typescript_type_text(items, indent_level);
+      match items.as_ref() {
+        PropertyKind::Enum(_) => format!("({})[]", items_text),
+        _ => format!("{}[]", items_text),
+      }
+    }
+    PropertyKind::Object(properties) => {
+      let mut text = "{\n".to_string();
+      write_typescript_properties(&mut text, properties, indent_level + 1);
+      text.push_str(&"  ".repeat(indent_level));
+      text.push('}');
+      text
+    }
+    PropertyKind::Map(values) => format!("Record<string, {}>", typescript_type_text(values, indent_level)),
+    PropertyKind::Union(kinds) => kinds
+      .iter()
+      .map(|kind| typescript_type_text(kind, indent_level))
+      .collect::<Vec<_>>()
+      .join(" | "),
+  }
+}
+
+fn default_value_text(value: &DefaultValue) -> String {
+  match value {
+    DefaultValue::Bool(value) => value.to_string(),
+    DefaultValue::Number(value) => value.to_string(),
+    DefaultValue::String(value) => format!("\"{}\"", value),
+    DefaultValue::Array(values) => format!(
+      "[{}]",
+      values.iter().map(default_value_text).collect::<Vec<_>>().join(", ")
+    ),
+  }
+}
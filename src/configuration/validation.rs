@@ -0,0 +1,91 @@
+use super::ImportModifier;
+use super::ImportSelector;
+
+/// Group entry that matches imports not matched by any other group.
+const UNKNOWN_GROUP: &str = "unknown";
+
+/// Verifies a `groups` entry is either a custom group name or a selector
+/// optionally prefixed by modifiers (ex. `type-external`).
+pub fn validate_group_entry(entry: &str, custom_group_names: &[&str]) -> Result<(), String> {
+  if entry == UNKNOWN_GROUP || custom_group_names.contains(&entry) {
+    return Ok(());
+  }
+
+  let mut parts = entry.split('-').collect::<Vec<_>>();
+  let selector = parts.pop().unwrap_or_default();
+  if selector.parse::<ImportSelector>().is_err() {
+    return Err(format!(
+      "Unknown group '{}'. Expected a custom group name or a selector optionally prefixed by modifiers (ex. 'type-external').",
+      entry
+    ));
+  }
+
+  let mut seen_modifiers = Vec::with_capacity(parts.len());
+  for modifier in parts {
+    if modifier.parse::<ImportModifier>().is_err() {
+      return Err(format!("Unknown modifier '{}' in group '{}'.", modifier, entry));
+    }
+    if seen_modifiers.contains(&modifier) {
+      return Err(format!("Duplicate modifier '{}' in group '{}'.", modifier, entry));
+    }
+    seen_modifiers.push(modifier);
+  }
+
+  Ok(())
+}
+
+/// Verifies a glob pattern is one Oxc's glob matcher accepts.
+pub fn validate_glob(pattern: &str) -> Result<(), String> {
+  validate_non_empty(pattern)?;
+  // the matcher doesn't report malformed patterns, it just never matches them
+  fast_glob::validate(pattern).map_err(|err| format!("Invalid glob pattern '{}': {}.", pattern, err))
+}
+
+/// Verifies a pattern that isn't a glob (ex. `internalPattern`) is not
+/// empty, since Oxc uses those as they are.
+pub fn validate_non_empty(pattern: &str) -> Result<(), String> {
+  if pattern.is_empty() {
+    return Err("Expected a non-empty pattern.".to_string());
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn validates_group_entries() {
+    assert!(validate_group_entry("builtin", &[]).is_ok());
+    assert!(validate_group_entry("type-external", &[]).is_ok());
+    assert!(validate_group_entry("value-default-internal", &[]).is_ok());
+    assert!(validate_group_entry("unknown", &[]).is_ok());
+    assert!(validate_group_entry("react", &["react"]).is_ok());
+    assert!(validate_group_entry("react", &[]).is_err());
+    assert!(validate_group_entry("externals", &[]).is_err());
+    assert!(validate_group_entry("typed-external", &[]).is_err());
+    assert!(validate_group_entry("type-type-external", &[]).is_err());
+  }
+
+  #[test]
+  fn validates_globs() {
+    assert!(validate_glob("react").is_ok());
+    assert!(validate_glob("react-*").is_ok());
+    assert!(validate_glob("@{org,other}/**").is_ok());
+    assert!(validate_glob("[!a-c]*").is_ok());
+    assert!(validate_glob("[]]").is_ok());
+    // an unmatched `}` is an ordinary character
+    assert!(validate_glob("a}").is_ok());
+    assert!(validate_glob("").is_err());
+    assert!(validate_glob("[abc").is_err());
+    assert!(validate_glob("{a,b").is_err());
+    assert!(validate_glob("a\\").is_err());
+  }
+
+  #[test]
+  fn validates_non_glob_patterns() {
+    assert!(validate_non_empty("~/").is_ok());
+    assert!(validate_non_empty("[abc").is_ok());
+    assert!(validate_non_empty("").is_err());
+  }
+}
@@ -0,0 +1,162 @@
+use std::path::Path;
+
+use crate::configuration::Configuration;
+use crate::format_text::FormatError;
+use crate::formatter::Formatter;
+use crate::token_alignment::align_tokens;
+
+/// How a text offset is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetEncoding {
+  /// Bytes of UTF-8.
+  Utf8,
+  /// UTF-16 code units, as used by JavaScript and the language server
+  /// protocol.
+  Utf16,
+}
+
+/// The result of formatting with a cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorFormatResult {
+  /// The formatted text, or `None` when the text was already formatted or
+  /// is not supported.
+  pub text: Option<String>,
+  /// Where the cursor is in the formatted text, in the same encoding as the
+  /// offset it was given in.
+  pub cursor_offset: usize,
+}
+
+/// Formats the text of a file and maps a cursor offset in the input to the
+/// corresponding offset in the formatted text, like Prettier's
+/// `formatWithCursor`.
+pub fn format_text_with_cursor(
+  file_path: &Path,
+  input_text: &str,
+  cursor_offset: usize,
+  encoding: OffsetEncoding,
+  config: &Configuration,
+) -> Result<CursorFormatResult, FormatError> {
+  Formatter::new(config.clone()).format_with_cursor(file_path, input_text, cursor_offset, encoding)
+}
+
+/// Maps an offset in the old text to the corresponding offset in the new
+/// text based on the tokens the two texts have in common.
+///
+/// An offset in or at the end of a token stays at the same place relative to
+/// that token. Between tokens, it keeps its distance from the end of the
+/// previous token without passing the next one.
+pub fn map_cursor_offset(old_text: &str, new_text: &str, offset: usize, encoding: OffsetEncoding) -> usize {
+  let old_offset = to_byte_offset(old_text, offset, encoding);
+  let aligned_tokens = align_tokens(old_text, new_text);
+  // the first token ending at or after the offset
+  let index = aligned_tokens.partition_point(|(old_range, _)| old_range.end < old_offset);
+
+  let new_offset = match aligned_tokens.get(index) {
+    Some((old_range, new_range)) if old_range.start <= old_offset => new_range.start + (old_offset - old_range.start),
+    next => {
+      let (previous_old_end, previous_new_end) = match index.checked_sub(1) {
+        Some(previous) => (aligned_tokens[previous].0.end, aligned_tokens[previous].1.end),
+        None => (0, 0),
+      };
+      let next_new_start = next.map(|(_, new_range)| new_range.start).unwrap_or(new_text.len());
+      let new_offset = (previous_new_end + (old_offset - previous_old_end)).min(next_new_start);
+      floor_char_boundary(new_text, new_offset)
+    }
+  };
+  from_byte_offset(new_text, new_offset, encoding)
+}
+
+fn to_byte_offset(text: &str, offset: usize, encoding: OffsetEncoding) -> usize {
+  match encoding {
+    OffsetEncoding::Utf8 => floor_char_boundary(text, offset.min(text.len())),
+    OffsetEncoding::Utf16 => {
+      let mut utf16_offset = 0;
+      for (byte_offset, c) in text.char_indices() {
+        utf16_offset += c.len_utf16();
+        // an offset in the middle of a surrogate pair is moved before it
+        if utf16_offset > offset {
+          return byte_offset;
+        }
+      }
+      text.len()
+    }
+  }
+}
+
+fn from_byte_offset(text: &str, byte_offset: usize, encoding: OffsetEncoding) -> usize {
+  match encoding {
+    OffsetEncoding::Utf8 => byte_offset,
+    OffsetEncoding::Utf16 => text[..byte_offset].chars().map(char::len_utf16).sum(),
+  }
+}
+
+fn floor_char_boundary(text: &str, mut offset: usize) -> usize {
+  while !text.is_char_boundary(offset) {
+    offset -= 1;
+  }
+  offset
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn maps_offsets_through_tokens() {
+    let map = |offset| {
+      map_cursor_offset(
+        "let foo=bar(1,2)",
+        "let foo = bar(1, 2);\n",
+        offset,
+        OffsetEncoding::Utf8,
+      )
+    };
+    // in a token
+    assert_eq!(map(5), 5);
+    assert_eq!(map(9), 11);
+    // at the end of a token
+    assert_eq!(map(7), 7);
+    assert_eq!(map(8), 9);
+    assert_eq!(map(16), 19);
+    // past the end of the text
+    assert_eq!(map(100), 19);
+  }
+
+  #[test]
+  fn maps_offsets_between_tokens() {
+    let map = |offset| map_cursor_offset("a;\n\n\n\nb;", "a;\n\nb;\n", offset, OffsetEncoding::Utf8);
+    assert_eq!(map(3), 3);
+    assert_eq!(map(5), 4);
+    assert_eq!(map(6), 4);
+    // unmatched tokens
+    let map = |offset| map_cursor_offset("x = 'abc'", "x = \"abc\";\n", offset, OffsetEncoding::Utf8);
+    assert_eq!(map(4), 4);
+    assert_eq!(map(5), 5);
+  }
+
+  #[test]
+  fn maps_utf16_offsets() {
+    let map = |offset| map_cursor_offset("a('😀',b)", "a(\"😀\", b);\n", offset, OffsetEncoding::Utf16);
+    assert_eq!(map(8), 9);
+    assert_eq!(map(3), 3);
+    // in the middle of a surrogate pair
+    assert_eq!(map(4), 3);
+  }
+
+  #[test]
+  fn formats_with_cursor() {
+    let config = Configuration::default();
+    let result = format_text_with_cursor(Path::new("file.ts"), "let a=1", 6, OffsetEncoding::Utf8, &config).unwrap();
+    assert_eq!(
+      result,
+      CursorFormatResult {
+        text: Some("let a = 1;\n".to_string()),
+        cursor_offset: 7,
+      }
+    );
+    let result =
+      format_text_with_cursor(Path::new("file.ts"), "let a = 1;\n", 4, OffsetEncoding::Utf8, &config).unwrap();
+    assert_eq!(result.text, None);
+    assert_eq!(result.cursor_offset, 4);
+  }
+}
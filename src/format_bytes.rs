@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use crate::configuration::Configuration;
+use crate::format_text::FormatError;
+use crate::formatter::Formatter;
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16_LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16_BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+#[derive(Clone, Copy)]
+enum Utf16Endianness {
+  Little,
+  Big,
+}
+
+/// Formats the bytes of a file.
+///
+/// Files are expected to be UTF-8 (with or without a byte order mark) or
+/// UTF-16 with a byte order mark. UTF-16 files are written back as UTF-16.
+pub fn format_bytes(
+  file_path: &Path,
+  file_bytes: &[u8],
+  config: &Configuration,
+) -> Result<Option<Vec<u8>>, FormatError> {
+  Formatter::new(config.clone()).format_bytes(file_path, file_bytes)
+}
+
+/// Decodes the bytes of a file, formats the text, then encodes the result
+/// the same way as the input.
+pub(crate) fn decode_and_format(
+  file_bytes: &[u8],
+  format_text: impl FnOnce(&str) -> Result<Option<String>, FormatError>,
+) -> Result<Option<Vec<u8>>, FormatError> {
+  let endianness = if file_bytes.starts_with(UTF8_BOM) {
+    None
+  } else if file_bytes.starts_with(UTF16_LE_BOM) {
+    Some(Utf16Endianness::Little)
+  } else if file_bytes.starts_with(UTF16_BE_BOM) {
+    Some(Utf16Endianness::Big)
+  } else {
+    None
+  };
+
+  match endianness {
+    None => {
+      let text = std::str::from_utf8(file_bytes).map_err(|err| {
+        format!(
+          "File is not valid UTF-8 ({}). Only UTF-8 files and UTF-16 files with a byte order mark are supported.",
+          err
+        )
+      })?;
+      Ok(format_text(text)?.map(|text| text.into_bytes()))
+    }
+    Some(endianness) => {
+      let text = decode_utf16(&file_bytes[2..], endianness)?;
+      Ok(format_text(&text)?.map(|text| encode_utf16(&text, endianness)))
+    }
+  }
+}
+
+fn decode_utf16(bytes: &[u8], endianness: Utf16Endianness) -> Result<String, FormatError> {
+  if bytes.len() % 2 != 0 {
+    return Err("File has a UTF-16 byte order mark, but an odd number of bytes.".into());
+  }
+  let units = bytes.chunks_exact(2).map(|chunk| {
+    let pair = [chunk[0], chunk[1]];
+    match endianness {
+      Utf16Endianness::Little => u16::from_le_bytes(pair),
+      Utf16Endianness::Big => u16::from_be_bytes(pair),
+    }
+  });
+  char::decode_utf16(units)
+    .collect::<Result<String, _>>()
+    .map_err(|err| format!("File is not valid UTF-16 ({}).", err).into())
+}
+
+fn encode_utf16(text: &str, endianness: Utf16Endianness) -> Vec<u8> {
+  let mut bytes = Vec::with_capacity(2 + text.len() * 2);
+  bytes.extend_from_slice(match endianness {
+    Utf16Endianness::Little => UTF16_LE_BOM,
+    Utf16Endianness::Big => UTF16_BE_BOM,
+  });
+  for unit in text.encode_utf16() {
+    bytes.extend_from_slice(&match endianness {
+      Utf16Endianness::Little => unit.to_le_bytes(),
+      Utf16Endianness::Big => unit.to_be_bytes(),
+    });
+  }
+  bytes
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::configuration::ByteOrderMark;
+
+  fn format(bytes: &[u8], config: &Configuration) -> Result<Vec<u8>, FormatError> {
+    format_bytes(Path::new("test.js"), bytes, config).map(|result| result.unwrap_or_else(|| bytes.to_vec()))
+  }
+
+  #[test]
+  fn preserves_utf8_bom() {
+    let input = [UTF8_BOM, b"const x=1"].concat();
+    let output = format(&input, &Configuration::default()).unwrap();
+    assert_eq!(output, [UTF8_BOM, b"const x = 1;\n"].concat());
+  }
+
+  #[test]
+  fn removes_utf8_bom_when_configured() {
+    let config = Configuration {
+      byte_order_mark: Some(ByteOrderMark::Remove),
+      ..Default::default()
+    };
+    let input = [UTF8_BOM, b"const x = 1;\n"].concat();
+    let output = format(&input, &config).unwrap();
+    assert_eq!(output, b"const x = 1;\n");
+  }
+
+  #[test]
+  fn round_trips_utf16() {
+    for endianness in [Utf16Endianness::Little, Utf16Endianness::Big] {
+      let input = encode_utf16("const x=\"é\"", endianness);
+      let output = format(&input, &Configuration::default()).unwrap();
+      assert_eq!(output, encode_utf16("const x = \"é\";\n", endianness));
+    }
+  }
+
+  #[test]
+  fn errors_on_invalid_utf8() {
+    let err = format(b"const x = \"\xFF\";\n", &Configuration::default()).unwrap_err();
+    assert!(err.to_string().starts_with("File is not valid UTF-8"), "{}", err);
+  }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+/// Approximate memory used by an entry in addition to its text.
+const ENTRY_OVERHEAD: usize = 64;
+
+/// Hit and miss counts of a [`FormatCache`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FormatCacheStats {
+  pub hits: u64,
+  pub misses: u64,
+  pub entries: usize,
+  pub used_bytes: usize,
+}
+
+/// Results of formatting keyed on a hash of the input text, source type and
+/// configuration. The oldest entries are evicted once the memory budget is
+/// exceeded.
+///
+/// Results for different configurations have different keys, so one cache
+/// can be shared by several formatters.
+pub(crate) struct FormatCache {
+  entries: HashMap<u64, CacheEntry>,
+  insertion_order: VecDeque<u64>,
+  memory_budget: usize,
+  stats: FormatCacheStats,
+}
+
+struct CacheEntry {
+  /// Guards against hash collisions, which would otherwise give the output
+  /// of another input.
+  input: String,
+  /// The formatted text or `None` when the input was already formatted.
+  output: Option<String>,
+}
+
+impl CacheEntry {
+  fn size(&self) -> usize {
+    ENTRY_OVERHEAD + self.input.len() + self.output.as_ref().map(|output| output.len()).unwrap_or(0)
+  }
+}
+
+impl FormatCache {
+  pub fn new(memory_budget: usize) -> Self {
+    Self {
+      entries: HashMap::new(),
+      insertion_order: VecDeque::new(),
+      memory_budget,
+      stats: FormatCacheStats::default(),
+    }
+  }
+
+  pub fn stats(&self) -> FormatCacheStats {
+    self.stats
+  }
+
+  pub fn get(&mut self, key: u64, input_text: &str) -> Option<Option<String>> {
+    match self.entries.get(&key) {
+      Some(entry) if entry.input == input_text => {
+        self.stats.hits += 1;
+        Some(entry.output.clone())
+      }
+      _ => {
+        self.stats.misses += 1;
+        None
+      }
+    }
+  }
+
+  pub fn insert(&mut self, key: u64, input_text: &str, output: Option<String>) {
+    let entry = CacheEntry {
+      input: input_text.to_string(),
+      output,
+    };
+    let size = entry.size();
+    if size > self.memory_budget {
+      return;
+    }
+    while self.stats.used_bytes + size > self.memory_budget {
+      let Some(oldest_key) = self.insertion_order.pop_front() else {
+        break;
+      };
+      self.remove(oldest_key);
+    }
+    if let Some(previous) = self.entries.insert(key, entry) {
+      self.stats.used_bytes -= previous.size();
+    } else {
+      self.insertion_order.push_back(key);
+    }
+    self.stats.used_bytes += size;
+    self.stats.entries = self.entries.len();
+  }
+
+  fn remove(&mut self, key: u64) {
+    if let Some(entry) = self.entries.remove(&key) {
+      self.stats.used_bytes -= entry.size();
+      self.stats.entries = self.entries.len();
+    }
+  }
+}
+
+/// Hashes a value with a hasher that gives the same result for the lifetime
+/// of the process.
+pub(crate) fn hash_value(value: impl Hash) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  value.hash(&mut hasher);
+  hasher.finish()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn evicts_oldest_entries_over_budget() {
+    let mut cache = FormatCache::new(ENTRY_OVERHEAD * 2 + 12);
+    cache.insert(1, "a", Some("12345".to_string()));
+    cache.insert(2, "b", None);
+    assert_eq!(cache.get(1, "a"), Some(Some("12345".to_string())));
+    assert_eq!(cache.get(2, "b"), Some(None));
+
+    cache.insert(3, "c", Some("1234567890".to_string()));
+    assert_eq!(cache.get(1, "a"), None);
+    assert_eq!(cache.get(2, "b"), Some(None));
+    assert_eq!(cache.get(3, "c"), Some(Some("1234567890".to_string())));
+    // different inputs with the same hash
+    assert_eq!(cache.get(3, "cc"), None);
+    assert_eq!(cache.get(3, "d"), None);
+
+    assert_eq!(
+      cache.stats(),
+      FormatCacheStats {
+        hits: 4,
+        misses: 3,
+        entries: 2,
+        used_bytes: ENTRY_OVERHEAD * 2 + 12,
+      }
+    );
+  }
+}
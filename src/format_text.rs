@@ -1,4 +1,3 @@
-use oxc_allocator::Allocator;
 use oxc_formatter::ArrowParentheses;
 use oxc_formatter::AttributePosition;
 use oxc_formatter::CommentLineStrategy;
@@ -23,54 +22,54 @@ use oxc_formatter_core::IndentWidth;
 use oxc_formatter_core::LineEnding;
 use oxc_formatter_core::LineWidth;
 use oxc_parser::ParseOptions;
-use oxc_parser::Parser;
-use oxc_span::SourceType;
 use std::path::Path;
 
 use crate::configuration::Configuration;
+use crate::formatter::Formatter;
 
-type FormatError = Box<dyn std::error::Error + Send + Sync>;
+pub type FormatError = Box<dyn std::error::Error + Send + Sync>;
 
+/// Formats the text of a file.
+///
+/// Use a [`Formatter`] instead when formatting many files with the same
+/// configuration.
 pub fn format_text(file_path: &Path, input_text: &str, config: &Configuration) -> Result<Option<String>, FormatError> {
-  let source_type = match SourceType::from_path(file_path) {
-    Ok(source_type) => source_type,
-    Err(_) => return Ok(None),
-  };
-
-  let allocator = Allocator::default();
-  let parse_options = ParseOptions {
-    preserve_parens: false,
-    ..Default::default()
-  };
-  let parsed = Parser::new(&allocator, input_text, source_type)
-    .with_options(parse_options)
-    .parse();
+  Formatter::new(config.clone()).format(file_path, input_text)
+}
 
-  if !parsed.diagnostics.is_empty() {
-    let mut error_text = String::new();
-    for (i, error) in parsed.diagnostics.iter().enumerate() {
-      if i > 0 {
-        error_text.push('\n');
+/// Gets the most common line ending in the text, defaulting to a line feed.
+pub(crate) fn detect_line_ending(text: &str) -> LineEnding {
+  let bytes = text.as_bytes();
+  let mut lf_count = 0;
+  let mut crlf_count = 0;
+  for (i, byte) in bytes.iter().enumerate() {
+    if *byte == b'\n' {
+      if i > 0 && bytes[i - 1] == b'\r' {
+        crlf_count += 1;
+      } else {
+        lf_count += 1;
       }
-      error_text.push_str(&error.to_string());
     }
-    return Err(error_text.into());
   }
-
-  let options = build_format_options(config);
-  let output = oxc_formatter::format_program(&allocator, &parsed.program, options)
-    .print()
-    .map_err(|e| e.to_string())?
-    .into_code();
-
-  if output == input_text {
-    Ok(None)
+  if crlf_count > lf_count {
+    LineEnding::Crlf
   } else {
-    Ok(Some(output))
+    LineEnding::Lf
   }
 }
 
-fn build_format_options(config: &Configuration) -> JsFormatOptions {
+pub(crate) fn build_parse_options(config: &Configuration) -> ParseOptions {
+  ParseOptions {
+    allow_return_outside_function: config.allow_return_outside_function.unwrap_or(false),
+    allow_v8_intrinsics: config.allow_v8_intrinsics.unwrap_or(false),
+    // not configurable (see `preserveParens`) because the formatter doesn't
+    // support parenthesized expression nodes
+    preserve_parens: false,
+    ..Default::default()
+  }
+}
+
+pub(crate) fn build_format_options(config: &Configuration) -> JsFormatOptions {
   let mut options = JsFormatOptions::default();
 
   if let Some(line_ending) = config.line_ending {
@@ -78,6 +77,16 @@ fn build_format_options(config: &Configuration) -> JsFormatOptions {
       crate::configuration::LineEnding::Lf => LineEnding::Lf,
       crate::configuration::LineEnding::Cr => LineEnding::Cr,
       crate::configuration::LineEnding::Crlf => LineEnding::Crlf,
+      // resolved for each file by the formatter
+      crate::configuration::LineEnding::Auto => options.line_ending,
+      // Wasm doesn't know the host OS, so this is always a line feed there
+      crate::configuration::LineEnding::System => {
+        if cfg!(windows) {
+          LineEnding::Crlf
+        } else {
+          LineEnding::Lf
+        }
+      }
     };
   }
 
@@ -292,4 +301,26 @@ mod test {
       .unwrap();
     assert_eq!(result, "const x = 1;\n");
   }
+
+  #[test]
+  fn auto_line_ending_uses_most_common() {
+    let config = crate::configuration::Configuration {
+      line_ending: Some(crate::configuration::LineEnding::Auto),
+      ..Default::default()
+    };
+    let format = |input: &str| {
+      format_text(std::path::Path::new("test.js"), input, &config)
+        .unwrap()
+        .unwrap_or_else(|| input.to_string())
+    };
+    assert_eq!(
+      format("let a=1\r\nlet b=2\r\nlet c=3\n"),
+      "let a = 1;\r\nlet b = 2;\r\nlet c = 3;\r\n"
+    );
+    assert_eq!(
+      format("let a=1\nlet b=2\r\nlet c=3\n"),
+      "let a = 1;\nlet b = 2;\nlet c = 3;\n"
+    );
+    assert_eq!(format("let a=1"), "let a = 1;\n");
+  }
 }
@@ -0,0 +1,613 @@
+use dprint_core::plugins::CancellationToken;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_formatter::JsFormatOptions;
+use oxc_parser::ParseOptions;
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use std::cell::RefCell;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+use crate::configuration::ByteOrderMark;
+use crate::configuration::Configuration;
+use crate::configuration::DEFAULT_MAX_NESTING_DEPTH;
+use crate::configuration::LineEnding;
+use crate::configuration::NestingDepthExceeded;
+use crate::configuration::VerifyIdempotency;
+use crate::cursor_offset::CursorFormatResult;
+use crate::cursor_offset::OffsetEncoding;
+use crate::cursor_offset::map_cursor_offset;
+use crate::format_bytes::decode_and_format;
+use crate::format_cache::FormatCache;
+use crate::format_cache::FormatCacheStats;
+use crate::format_cache::hash_value;
+use crate::format_text::FormatError;
+use crate::format_text::build_format_options;
+use crate::format_text::build_parse_options;
+use crate::format_text::detect_line_ending;
+use crate::nesting_depth::find_deep_brackets;
+use crate::nesting_depth::find_deep_node;
+use crate::nesting_depth::line_and_column;
+use crate::panics::catch_panic;
+use crate::source_type::is_jsx_in_js;
+use crate::source_type::resolve_source_type;
+use crate::text_edits::TextEdit;
+use crate::text_edits::compute_text_edits;
+use crate::verify_comments::CommentDifference;
+use crate::verify_comments::find_comment_difference;
+use crate::verify_idempotency::unstable_region_diff;
+use crate::verify_semantics::find_semantic_difference;
+
+const BOM_CHAR: char = '\u{FEFF}';
+
+/// Allocators that grew beyond this while formatting a file are dropped
+/// instead of reset so an unusually large file doesn't hold on to its memory
+/// for the rest of the run.
+const MAX_RETAINED_ALLOCATOR_CAPACITY: usize = 64 * 1024 * 1024;
+
+thread_local! {
+  static ALLOCATOR: RefCell<Allocator> = RefCell::new(Allocator::default());
+}
+
+/// Formats files with a configuration, building Oxc's options once rather
+/// than for every file.
+pub struct Formatter {
+  config: Configuration,
+  parse_options: ParseOptions,
+  format_options: JsFormatOptions,
+  /// Hash of the configuration for the cache keys, which is only computed
+  /// when a cache is attached.
+  config_fingerprint: u64,
+  cache: Option<Arc<Mutex<FormatCache>>>,
+  #[cfg(not(target_arch = "wasm32"))]
+  time_budget: Option<Duration>,
+}
+
+impl Formatter {
+  pub fn new(config: Configuration) -> Self {
+    Self {
+      parse_options: build_parse_options(&config),
+      format_options: build_format_options(&config),
+      config_fingerprint: 0,
+      config,
+      cache: None,
+      #[cfg(not(target_arch = "wasm32"))]
+      time_budget: None,
+    }
+  }
+
+  /// Caches the results of formatting so unchanged inputs are not formatted
+  /// again, using up to roughly `memory_budget` bytes.
+  pub fn with_cache(mut self, memory_budget: usize) -> Self {
+    self.config_fingerprint = hash_value(&self.config);
+    self.cache = Some(Arc::new(Mutex::new(FormatCache::new(memory_budget))));
+    self
+  }
+
+  /// Caches the results of formatting in a cache that other formatters may
+  /// also use.
+  #[cfg(any(feature = "wasm", feature = "process"))]
+  pub(crate) fn with_shared_cache(mut self, cache: Arc<Mutex<FormatCache>>) -> Self {
+    self.config_fingerprint = hash_value(&self.config);
+    self.cache = Some(cache);
+    self
+  }
+
+  /// Errors when formatting a file takes longer than the time budget. This is
+  /// checked between parsing, formatting, and printing, so it can be exceeded
+  /// by the duration of a single phase.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn with_time_budget(mut self, time_budget: Duration) -> Self {
+    self.time_budget = Some(time_budget);
+    self
+  }
+
+  pub fn config(&self) -> &Configuration {
+    &self.config
+  }
+
+  /// Gets the hit and miss counts of the cache when it's enabled.
+  pub fn cache_stats(&self) -> Option<FormatCacheStats> {
+    self.cache.as_ref().map(|cache| cache.lock().unwrap().stats())
+  }
+
+  /// Formats the text of a file, returning `None` when the file is not
+  /// supported or is already formatted.
+  pub fn format(&self, file_path: &Path, input_text: &str) -> Result<Option<String>, FormatError> {
+    self.format_with_checkpoint(file_path, input_text, &self.checkpoint(None))
+  }
+
+  /// Formats the text of a file, stopping with an error once the token is
+  /// cancelled.
+  pub fn format_with_token(
+    &self,
+    file_path: &Path,
+    input_text: &str,
+    token: &dyn CancellationToken,
+  ) -> Result<Option<String>, FormatError> {
+    self.format_with_checkpoint(file_path, input_text, &self.checkpoint(Some(token)))
+  }
+
+  /// Formats text with an explicit source type instead of one based on a
+  /// file path, returning `None` when the text is already formatted.
+  pub fn format_with_source_type(
+    &self,
+    source_type: SourceType,
+    input_text: &str,
+  ) -> Result<Option<String>, FormatError> {
+    catch_panic("text", || {
+      self.format_inner(source_type, input_text, false, &self.checkpoint(None))
+    })
+  }
+
+  /// Formats the text of a file, returning the edits that turn it into the
+  /// formatted text rather than the whole formatted text.
+  pub fn format_edits(&self, file_path: &Path, input_text: &str) -> Result<Vec<TextEdit>, FormatError> {
+    Ok(match self.format(file_path, input_text)? {
+      Some(output) => compute_text_edits(input_text, &output),
+      None => Vec::new(),
+    })
+  }
+
+  /// Formats the text of a file and maps a cursor offset in it to the
+  /// corresponding offset in the formatted text.
+  pub fn format_with_cursor(
+    &self,
+    file_path: &Path,
+    input_text: &str,
+    cursor_offset: usize,
+    encoding: OffsetEncoding,
+  ) -> Result<CursorFormatResult, FormatError> {
+    let text = self.format(file_path, input_text)?;
+    let cursor_offset = match &text {
+      Some(output) => map_cursor_offset(input_text, output, cursor_offset, encoding),
+      None => cursor_offset,
+    };
+    Ok(CursorFormatResult { text, cursor_offset })
+  }
+
+  /// Formats the bytes of a file, which are expected to be UTF-8 (with or
+  /// without a byte order mark) or UTF-16 with a byte order mark.
+  pub fn format_bytes(&self, file_path: &Path, file_bytes: &[u8]) -> Result<Option<Vec<u8>>, FormatError> {
+    decode_and_format(file_bytes, |text| self.format(file_path, text))
+  }
+
+  /// Formats the bytes of a file, stopping with an error once the token is
+  /// cancelled.
+  pub fn format_bytes_with_token(
+    &self,
+    file_path: &Path,
+    file_bytes: &[u8],
+    token: &dyn CancellationToken,
+  ) -> Result<Option<Vec<u8>>, FormatError> {
+    decode_and_format(file_bytes, |text| self.format_with_token(file_path, text, token))
+  }
+
+  fn checkpoint<'a>(&self, token: Option<&'a dyn CancellationToken>) -> Checkpoint<'a> {
+    Checkpoint {
+      token,
+      #[cfg(not(target_arch = "wasm32"))]
+      deadline: self
+        .time_budget
+        .map(|time_budget| (Instant::now() + time_budget, time_budget)),
+    }
+  }
+
+  fn format_with_checkpoint(
+    &self,
+    file_path: &Path,
+    input_text: &str,
+    checkpoint: &Checkpoint,
+  ) -> Result<Option<String>, FormatError> {
+    catch_panic(&file_path.display().to_string(), || {
+      let text = strip_bom(input_text);
+      let Some(source_type) = resolve_source_type(file_path, text, &self.config) else {
+        return Ok(None);
+      };
+      self.format_inner(
+        source_type,
+        input_text,
+        is_jsx_in_js(file_path, &self.config),
+        checkpoint,
+      )
+    })
+  }
+
+  fn format_inner(
+    &self,
+    source_type: SourceType,
+    input_text: &str,
+    retry_without_jsx: bool,
+    checkpoint: &Checkpoint,
+  ) -> Result<Option<String>, FormatError> {
+    let format = || {
+      with_allocator(|allocator| {
+        self.format_with_allocator(allocator, source_type, input_text, retry_without_jsx, checkpoint)
+      })
+    };
+    let Some(cache) = &self.cache else {
+      return format();
+    };
+
+    let key = hash_value((input_text, source_type, retry_without_jsx, self.config_fingerprint));
+    if let Some(result) = cache.lock().unwrap().get(key, input_text) {
+      return Ok(result);
+    }
+    // errors aren't cached so they're reported every time
+    let result = format()?;
+    cache.lock().unwrap().insert(key, input_text, result.clone());
+    Ok(result)
+  }
+
+  fn format_with_allocator(
+    &self,
+    allocator: &Allocator,
+    source_type: SourceType,
+    input_text: &str,
+    retry_without_jsx: bool,
+    checkpoint: &Checkpoint,
+  ) -> Result<Option<String>, FormatError> {
+    // the BOM is not part of the program, so format the text after it
+    let text = strip_bom(input_text);
+    let has_bom = text.len() != input_text.len();
+
+    checkpoint.check()?;
+    // deeply nested code can overflow the stack in the parser and formatter,
+    // so check the brackets before parsing and the syntax nodes after
+    let max_nesting_depth = self.config.max_nesting_depth.unwrap_or(DEFAULT_MAX_NESTING_DEPTH);
+    if let Some(offset) = find_deep_brackets(text, max_nesting_depth) {
+      return self.nesting_depth_exceeded(text, offset, max_nesting_depth);
+    }
+    let Some(output) = self.format_source(allocator, source_type, text, retry_without_jsx, checkpoint)? else {
+      return Ok(None);
+    };
+    let output = self.verify_idempotency(allocator, source_type, output, retry_without_jsx, checkpoint)?;
+    let output = if has_bom && self.config.byte_order_mark != Some(ByteOrderMark::Remove) {
+      format!("{}{}", BOM_CHAR, output)
+    } else {
+      output
+    };
+
+    if output == input_text {
+      Ok(None)
+    } else {
+      Ok(Some(output))
+    }
+  }
+
+  /// Parses and formats source text without a BOM, returning `None` when
+  /// it's skipped for being too deeply nested.
+  fn format_source(
+    &self,
+    allocator: &Allocator,
+    source_type: SourceType,
+    text: &str,
+    retry_without_jsx: bool,
+    checkpoint: &Checkpoint,
+  ) -> Result<Option<String>, FormatError> {
+    let parse = |source_type| {
+      Parser::new(allocator, text, source_type)
+        .with_options(self.parse_options)
+        .parse()
+    };
+    let mut source_type = source_type;
+    let mut parsed = parse(source_type);
+    // JSX in .js files is opt-in, so fall back to plain JavaScript when the
+    // file only parses without it (ex. `a <b && c> d`)
+    if !parsed.diagnostics.is_empty() && retry_without_jsx && source_type.is_jsx() {
+      let retried = parse(source_type.with_jsx(false));
+      if retried.diagnostics.is_empty() {
+        parsed = retried;
+        source_type = source_type.with_jsx(false);
+      }
+    }
+
+    if !parsed.diagnostics.is_empty() {
+      let mut error_text = String::new();
+      for (i, error) in parsed.diagnostics.iter().enumerate() {
+        if i > 0 {
+          error_text.push('\n');
+        }
+        error_text.push_str(&error.to_string());
+      }
+      return Err(error_text.into());
+    }
+    let max_nesting_depth = self.config.max_nesting_depth.unwrap_or(DEFAULT_MAX_NESTING_DEPTH);
+    if let Some(offset) = find_deep_node(&parsed.program, max_nesting_depth) {
+      return self.nesting_depth_exceeded(text, offset, max_nesting_depth);
+    }
+
+    checkpoint.check()?;
+
+    let mut options = self.format_options.clone();
+    if self.config.line_ending == Some(LineEnding::Auto) {
+      options.line_ending = detect_line_ending(text);
+    }
+    let formatted = oxc_formatter::format_program(allocator, &parsed.program, options);
+    checkpoint.check()?;
+    let output = formatted.print().map_err(|e| e.to_string())?.into_code();
+    if self.config.verify_semantics == Some(true) || self.config.verify_comments == Some(true) {
+      checkpoint.check()?;
+      self.verify_output(allocator, source_type, text, &parsed.program, &output)?;
+    }
+    Ok(Some(output))
+  }
+
+  /// Parses the formatted output and checks its syntax tree and comments
+  /// against the input program.
+  fn verify_output(
+    &self,
+    allocator: &Allocator,
+    source_type: SourceType,
+    input_text: &str,
+    input_program: &Program,
+    output: &str,
+  ) -> Result<(), FormatError> {
+    let parsed_output = Parser::new(allocator, output, source_type)
+      .with_options(self.parse_options)
+      .parse();
+    if let Some(error) = parsed_output.diagnostics.first() {
+      return Err(
+        format!(
+          "Formatting produced output that does not parse. This is likely a bug in Oxc.\n\n{}",
+          error
+        )
+        .into(),
+      );
+    }
+
+    if self.config.verify_semantics == Some(true)
+      && let Some(difference) = find_semantic_difference(input_program, &parsed_output.program, &self.config)
+    {
+      let (line, column) = line_and_column(input_text, difference.input_offset);
+      return Err(
+        format!(
+          "Formatting changed the meaning of the code at line {}, column {}, so the file was not formatted. This is likely a bug in Oxc.\n\nExpected: {}\nFound: {}",
+          line, column, difference.expected, difference.found
+        )
+        .into(),
+      );
+    }
+
+    if self.config.verify_comments == Some(true)
+      && let Some(difference) = find_comment_difference(
+        input_text,
+        input_program,
+        output,
+        &parsed_output.program,
+        self.config.jsdoc.is_some(),
+      )
+    {
+      let message = match difference {
+        CommentDifference::Missing { text, input_offset } => {
+          let (line, column) = line_and_column(input_text, input_offset);
+          format!(
+            "Formatting lost the comment at line {}, column {}:\n\n{}",
+            line, column, text
+          )
+        }
+        CommentDifference::Duplicated { text, input_offset } => {
+          let (line, column) = line_and_column(input_text, input_offset);
+          format!(
+            "Formatting duplicated the comment at line {}, column {}:\n\n{}",
+            line, column, text
+          )
+        }
+        CommentDifference::Added { text } => {
+          format!("Formatting added a comment that is not in the input:\n\n{}", text)
+        }
+      };
+      return Err(
+        format!(
+          "{}\n\nThe file was not formatted. This is likely a bug in Oxc.",
+          message
+        )
+        .into(),
+      );
+    }
+    Ok(())
+  }
+
+  /// Formats the output again when `verifyIdempotency` is enabled, since Oxc
+  /// occasionally produces output that changes on a second pass.
+  fn verify_idempotency(
+    &self,
+    allocator: &Allocator,
+    source_type: SourceType,
+    output: String,
+    retry_without_jsx: bool,
+    checkpoint: &Checkpoint,
+  ) -> Result<String, FormatError> {
+    let mode = self.config.verify_idempotency.unwrap_or(VerifyIdempotency::Off);
+    if mode == VerifyIdempotency::Off {
+      return Ok(output);
+    }
+    let second_pass = self
+      .format_source(allocator, source_type, &output, retry_without_jsx, checkpoint)
+      .map_err(|err| {
+        format!(
+          "Formatting produced output that could not be formatted again.\n\n{}",
+          err
+        )
+      })?;
+    match second_pass {
+      Some(second_pass) if second_pass != output => match mode {
+        VerifyIdempotency::Error => Err(
+          format!(
+            "Formatting is not stable because formatting the output again changed it. This is likely a bug in Oxc. Set `verifyIdempotency` to \"secondPass\" to use the second result instead.\n\n{}",
+            unstable_region_diff(&output, &second_pass)
+          )
+          .into(),
+        ),
+        VerifyIdempotency::Off | VerifyIdempotency::SecondPass => Ok(second_pass),
+      },
+      _ => Ok(output),
+    }
+  }
+
+  fn nesting_depth_exceeded(
+    &self,
+    text: &str,
+    offset: usize,
+    max_nesting_depth: u32,
+  ) -> Result<Option<String>, FormatError> {
+    if self.config.nesting_depth_exceeded == Some(NestingDepthExceeded::Skip) {
+      return Ok(None);
+    }
+    let (line, column) = line_and_column(text, offset);
+    Err(
+      format!(
+        "Not formatting because the code at line {}, column {} is nested more than {} levels deep, which could overflow the stack. Increase `maxNestingDepth` or set `nestingDepthExceeded` to \"skip\" to leave files like this unformatted.",
+        line, column, max_nesting_depth
+      )
+      .into(),
+    )
+  }
+}
+
+/// Checked between the phases of formatting a file so it can stop early.
+struct Checkpoint<'a> {
+  token: Option<&'a dyn CancellationToken>,
+  /// When formatting must be done by, along with the time budget. Wasm has
+  /// no clock, so it doesn't support time budgets.
+  #[cfg(not(target_arch = "wasm32"))]
+  deadline: Option<(Instant, Duration)>,
+}
+
+impl Checkpoint<'_> {
+  fn check(&self) -> Result<(), FormatError> {
+    if self.token.is_some_and(|token| token.is_cancelled()) {
+      return Err("Formatting was cancelled.".into());
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some((deadline, time_budget)) = self.deadline
+      && Instant::now() >= deadline
+    {
+      return Err(format!("Formatting timed out after {}ms.", time_budget.as_millis()).into());
+    }
+    Ok(())
+  }
+}
+
+/// Runs the closure with this thread's allocator, resetting it afterwards so
+/// its memory is reused for the next file.
+fn with_allocator<T>(f: impl FnOnce(&Allocator) -> T) -> T {
+  ALLOCATOR.with(|cell| {
+    // a nested call (ex. while formatting embedded code) gets its own allocator
+    let Ok(mut allocator) = cell.try_borrow_mut() else {
+      return f(&Allocator::default());
+    };
+    // if a previous file panicked, its allocations were never released
+    if allocator.used_bytes() > 0 {
+      allocator.reset();
+    }
+    let result = f(&allocator);
+    if allocator.capacity() > MAX_RETAINED_ALLOCATOR_CAPACITY {
+      *allocator = Allocator::default();
+    } else {
+      allocator.reset();
+    }
+    result
+  })
+}
+
+fn strip_bom(text: &str) -> &str {
+  text.strip_prefix(BOM_CHAR).unwrap_or(text)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn formats_with_source_type() {
+    let formatter = Formatter::new(Configuration::default());
+    let result = formatter
+      .format_with_source_type(SourceType::tsx(), "const x=<T,>(a:T)=><div/>")
+      .unwrap()
+      .unwrap();
+    assert_eq!(result, "const x = <T,>(a: T) => <div />;\n");
+    assert!(
+      formatter
+        .format_with_source_type(SourceType::mjs(), "const x = 1;\n")
+        .unwrap()
+        .is_none()
+    );
+  }
+
+  #[test]
+  fn reuses_the_thread_allocator() {
+    let formatter = Formatter::new(Configuration::default());
+    for _ in 0..2 {
+      let result = formatter
+        .format_with_source_type(SourceType::mjs(), "const x=1")
+        .unwrap();
+      assert_eq!(result.unwrap(), "const x = 1;\n");
+      ALLOCATOR.with(|cell| {
+        let allocator = cell.borrow();
+        assert!(allocator.capacity() > 0);
+        assert_eq!(allocator.used_bytes(), 0);
+      });
+    }
+
+    // nested calls can't share the thread's allocator
+    let result = with_allocator(|_| formatter.format_with_source_type(SourceType::mjs(), "let y=2").unwrap());
+    assert_eq!(result.unwrap(), "let y = 2;\n");
+  }
+
+  #[test]
+  fn caches_results() {
+    let formatter = Formatter::new(Configuration::default()).with_cache(1024 * 1024);
+    let path = Path::new("file.ts");
+    for _ in 0..2 {
+      assert_eq!(formatter.format(path, "let a=1").unwrap().unwrap(), "let a = 1;\n");
+      assert!(formatter.format(path, "let a = 1;\n").unwrap().is_none());
+      assert!(formatter.format(path, "let a =").is_err());
+    }
+    let stats = formatter.cache_stats().unwrap();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (2, 4, 2));
+
+    // the source type is part of the key
+    assert!(
+      formatter
+        .format(Path::new("file.tsx"), "let a = 1;\n")
+        .unwrap()
+        .is_none()
+    );
+    assert_eq!(formatter.cache_stats().unwrap().misses, 5);
+  }
+
+  #[derive(Debug)]
+  struct CancelledToken;
+
+  impl CancellationToken for CancelledToken {
+    fn is_cancelled(&self) -> bool {
+      true
+    }
+
+    #[cfg(feature = "process")]
+    fn wait_cancellation(&self) -> dprint_core::async_runtime::LocalBoxFuture<'static, ()> {
+      Box::pin(std::future::ready(()))
+    }
+  }
+
+  #[test]
+  fn stops_when_cancelled_or_out_of_time() {
+    let path = Path::new("file.ts");
+    let formatter = Formatter::new(Configuration::default());
+    let err = formatter
+      .format_with_token(path, "let a=1", &CancelledToken)
+      .unwrap_err();
+    assert_eq!(err.to_string(), "Formatting was cancelled.");
+
+    let formatter = Formatter::new(Configuration::default()).with_time_budget(Duration::ZERO);
+    let err = formatter.format(path, "let a=1").unwrap_err();
+    assert_eq!(err.to_string(), "Formatting timed out after 0ms.");
+  }
+}
@@ -1,7 +1,37 @@
 pub mod configuration;
+mod cursor_offset;
+mod format_bytes;
+mod format_cache;
 mod format_text;
+mod formatter;
+mod nesting_depth;
+mod panics;
+#[cfg(any(feature = "wasm", feature = "process"))]
+mod plugin_handler;
+#[cfg(feature = "process")]
+mod process_plugin;
+mod source_type;
+mod text_edits;
+mod token_alignment;
+mod verify_comments;
+mod verify_idempotency;
+mod verify_semantics;
 
+pub use cursor_offset::CursorFormatResult;
+pub use cursor_offset::OffsetEncoding;
+pub use cursor_offset::format_text_with_cursor;
+pub use cursor_offset::map_cursor_offset;
+pub use format_bytes::format_bytes;
+pub use format_cache::FormatCacheStats;
+pub use format_text::FormatError;
 pub use format_text::format_text;
+pub use formatter::Formatter;
+pub use oxc_span::SourceType;
+#[cfg(feature = "process")]
+pub use process_plugin::OxcProcessPluginHandler;
+pub use text_edits::TextEdit;
+pub use text_edits::compute_text_edits;
+pub use text_edits::format_text_edits;
 
 #[cfg(feature = "wasm")]
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
@@ -0,0 +1,322 @@
+use oxc_ast::ast::Expression;
+use oxc_ast::ast::JSXChild;
+use oxc_ast::ast::Program;
+use oxc_ast::ast::Statement;
+use oxc_ast::ast::TSType;
+use oxc_ast_visit::Visit;
+use oxc_ast_visit::walk;
+use oxc_span::GetSpan;
+
+/// Finds the byte offset of the first bracket nested deeper than the maximum
+/// depth.
+///
+/// This runs before parsing because the parser recurses on brackets, so it
+/// only lexes what affects brackets: strings, template literals, comments and
+/// regular expressions. It can't tell JSX text apart from code, so a quote
+/// with no closing quote on the same line isn't treated as a string and the
+/// open brackets are dropped at each line that starts a top-level
+/// declaration, which keeps a miscount from adding up over a large file.
+pub fn find_deep_brackets(text: &str, max_depth: u32) -> Option<usize> {
+  // the open brackets, where a backtick is a template literal
+  let mut stack: Vec<u8> = Vec::new();
+  let bytes = text.as_bytes();
+  let mut i = 0;
+  while i < bytes.len() {
+    let byte = bytes[i];
+    if stack.last() == Some(&b'`') {
+      match byte {
+        b'\\' => i += 1,
+        b'`' => {
+          stack.pop();
+        }
+        b'$' if bytes.get(i + 1) == Some(&b'{') => {
+          i += 1;
+          stack.push(b'{');
+        }
+        _ => {}
+      }
+    } else {
+      match byte {
+        b'(' | b'[' | b'{' | b'`' => stack.push(byte),
+        b')' | b']' | b'}' => {
+          stack.pop();
+        }
+        b'\'' | b'"' => i = skip_string(bytes, i).unwrap_or(i),
+        b'/' if bytes.get(i + 1) == Some(&b'/') => {
+          // stop before the newline so it's checked for a top-level declaration
+          i = bytes[i..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map(|pos| i + pos - 1)
+            .unwrap_or(bytes.len());
+        }
+        b'/' if bytes.get(i + 1) == Some(&b'*') => {
+          i = text[i + 2..].find("*/").map(|pos| i + pos + 3).unwrap_or(bytes.len());
+        }
+        b'/' if is_regex_allowed(bytes, i) => i = skip_regex(bytes, i).unwrap_or(i),
+        b'\n' if starts_top_level_declaration(&bytes[i + 1..]) && !stack.contains(&b'`') => stack.clear(),
+        _ => {}
+      }
+    }
+    if stack.len() > max_depth as usize {
+      return Some(i);
+    }
+    i += 1;
+  }
+  None
+}
+
+/// Gets the index of the closing quote of the string starting at `start`, or
+/// `None` when the line ends first, which happens for apostrophes in JSX text.
+fn skip_string(bytes: &[u8], start: usize) -> Option<usize> {
+  let quote = bytes[start];
+  let mut i = start + 1;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 1,
+      b'\n' => return None,
+      byte if byte == quote => return Some(i),
+      _ => {}
+    }
+    i += 1;
+  }
+  None
+}
+
+/// Gets the index of the closing slash of the regular expression starting at
+/// `start`, or `None` when the line ends first.
+fn skip_regex(bytes: &[u8], start: usize) -> Option<usize> {
+  let mut in_class = false;
+  let mut i = start + 1;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 1,
+      b'\n' => return None,
+      b'[' => in_class = true,
+      b']' => in_class = false,
+      b'/' if !in_class => return Some(i),
+      _ => {}
+    }
+    i += 1;
+  }
+  None
+}
+
+/// Keywords that may be followed by a regular expression rather than a
+/// division.
+const KEYWORDS_BEFORE_REGEX: [&[u8]; 14] = [
+  b"await",
+  b"case",
+  b"delete",
+  b"do",
+  b"else",
+  b"in",
+  b"instanceof",
+  b"new",
+  b"of",
+  b"return",
+  b"throw",
+  b"typeof",
+  b"void",
+  b"yield",
+];
+
+/// Gets if the slash at `index` starts a regular expression, which is when it
+/// doesn't follow a value (ex. an identifier, string or closing bracket) or
+/// start a JSX closing tag.
+fn is_regex_allowed(bytes: &[u8], index: usize) -> bool {
+  let before = bytes[..index].trim_ascii_end();
+  match before.last() {
+    None => true,
+    Some(b')' | b']' | b'}' | b'\'' | b'"' | b'`' | b'<') => false,
+    Some(byte) if is_identifier_byte(*byte) => {
+      let word_start = before
+        .iter()
+        .rposition(|byte| !is_identifier_byte(*byte))
+        .map(|pos| pos + 1)
+        .unwrap_or(0);
+      KEYWORDS_BEFORE_REGEX.contains(&&before[word_start..])
+    }
+    Some(_) => true,
+  }
+}
+
+/// Keywords that start a declaration, which is a top-level statement when it
+/// begins a line.
+const DECLARATION_KEYWORDS: [&[u8]; 11] = [
+  b"class",
+  b"const",
+  b"declare",
+  b"enum",
+  b"export",
+  b"function",
+  b"import",
+  b"interface",
+  b"let",
+  b"type",
+  b"var",
+];
+
+fn starts_top_level_declaration(line: &[u8]) -> bool {
+  DECLARATION_KEYWORDS
+    .iter()
+    .any(|keyword| line.starts_with(keyword) && line.get(keyword.len()).is_some_and(|byte| byte.is_ascii_whitespace()))
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+  byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || !byte.is_ascii()
+}
+
+/// Finds the byte offset of the first syntax node nested deeper than the
+/// maximum depth, which catches deep nesting without brackets such as long
+/// chains of binary expressions or JSX elements.
+///
+/// Only expressions, statements other than blocks, JSX elements and types
+/// count as a level, so a level is about one bracket in the source and the
+/// same maximum works for both checks.
+pub fn find_deep_node(program: &Program, max_depth: u32) -> Option<usize> {
+  let mut visitor = DepthVisitor {
+    max_depth,
+    depth: 0,
+    deep_node_start: None,
+  };
+  visitor.visit_program(program);
+  visitor.deep_node_start.map(|start| start as usize)
+}
+
+struct DepthVisitor {
+  max_depth: u32,
+  depth: u32,
+  deep_node_start: Option<u32>,
+}
+
+impl DepthVisitor {
+  fn is_too_deep(&self) -> bool {
+    self.deep_node_start.is_some()
+  }
+
+  /// Walks a node one level deeper, or records it without descending when
+  /// that exceeds the limit because the visitor recurses too.
+  fn walk_nested(&mut self, start: u32, walk: impl FnOnce(&mut Self)) {
+    if self.is_too_deep() {
+      return;
+    }
+    self.depth += 1;
+    if self.depth > self.max_depth {
+      self.deep_node_start = Some(start);
+    } else {
+      walk(self);
+    }
+    self.depth -= 1;
+  }
+}
+
+impl<'a> Visit<'a> for DepthVisitor {
+  fn visit_statement(&mut self, it: &Statement<'a>) {
+    if matches!(it, Statement::BlockStatement(_)) {
+      if !self.is_too_deep() {
+        walk::walk_statement(self, it);
+      }
+    } else {
+      self.walk_nested(it.span().start, |visitor| walk::walk_statement(visitor, it));
+    }
+  }
+
+  fn visit_expression(&mut self, it: &Expression<'a>) {
+    self.walk_nested(it.span().start, |visitor| walk::walk_expression(visitor, it));
+  }
+
+  fn visit_jsx_child(&mut self, it: &JSXChild<'a>) {
+    match it {
+      JSXChild::Element(_) | JSXChild::Fragment(_) => {
+        self.walk_nested(it.span().start, |visitor| walk::walk_jsx_child(visitor, it));
+      }
+      _ => walk::walk_jsx_child(self, it),
+    }
+  }
+
+  fn visit_ts_type(&mut self, it: &TSType<'a>) {
+    self.walk_nested(it.span().start, |visitor| walk::walk_ts_type(visitor, it));
+  }
+}
+
+/// Gets the one-based line and column (in characters) of a byte offset.
+pub fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+  let before = &text[..offset];
+  let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+  (
+    before.matches('\n').count() + 1,
+    before[line_start..].chars().count() + 1,
+  )
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use oxc_allocator::Allocator;
+  use oxc_parser::Parser;
+  use oxc_span::SourceType;
+
+  #[test]
+  fn finds_deep_brackets() {
+    assert_eq!(find_deep_brackets("[[[1]]]", 3), None);
+    assert_eq!(find_deep_brackets("[[[[1]]]]", 3), Some(3));
+    assert_eq!(find_deep_brackets("f(`${[{a: 1}]}`)", 4), Some(6));
+    // brackets in strings and comments don't count
+    assert_eq!(
+      find_deep_brackets("'((((' + \"[[[[\" // {{{{\n/* (((( */ `((((`", 1),
+      None
+    );
+  }
+
+  #[test]
+  fn skips_regular_expressions() {
+    let text =
+      "const a = /\\(/;\nconst b = /[(]/g.test(x) ? 1 : 2;\nfunction f() {\n  return /\\[/;\n}\n".repeat(1_000);
+    assert_eq!(find_deep_brackets(&text, 3), None);
+    // a slash after a value is a division
+    assert_eq!(find_deep_brackets("a / (b / [c / {d}]) / e", 3), None);
+    assert_eq!(find_deep_brackets("a / (b / [c / {d: [1]}])", 3), Some(18));
+    assert_eq!(find_deep_brackets("x = /(/; [[[[1]]]]", 3), Some(12));
+  }
+
+  #[test]
+  fn resyncs_after_jsx_text() {
+    // an apostrophe in JSX text doesn't start a string
+    let text = "const a = (<p>Don't</p>);\nconst b = <div>{items.map(i => (<li>It's {i}</li>))}</div>;\n".repeat(1_000);
+    assert_eq!(find_deep_brackets(&text, 4), None);
+    // brackets in JSX text are dropped at the next top-level declaration
+    let text = "export const a = <p>(note</p>;\n".repeat(1_000);
+    assert_eq!(find_deep_brackets(&text, 3), None);
+    // but not inside a template literal
+    assert_eq!(find_deep_brackets("`${[[\nconst a = [[1]]]]}`", 5), Some(17));
+  }
+
+  #[test]
+  fn counts_nesting_nodes() {
+    fn find(file_name: &str, text: &str, max_depth: u32) -> Option<usize> {
+      let allocator = Allocator::default();
+      let source_type = SourceType::from_path(file_name).unwrap();
+      let parsed = Parser::new(&allocator, text, source_type).parse();
+      assert!(parsed.diagnostics.is_empty());
+      find_deep_node(&parsed.program, max_depth)
+    }
+
+    // the declaration, two arrays and the number
+    assert_eq!(find("file.ts", "const x = [[1]];", 4), None);
+    assert_eq!(find("file.ts", "const x = [[1]];", 3), Some(12));
+    // blocks don't add a level to the statements in them
+    assert_eq!(find("file.ts", "if (a) { if (b) { c; } }", 4), None);
+    assert_eq!(find("file.ts", "if (a) { if (b) { c; } }", 3), Some(18));
+    assert_eq!(find("file.ts", "const x = a + b + c;", 3), Some(10));
+    assert_eq!(find("file.tsx", "const x = <a><b><c /></b></a>;", 3), Some(16));
+    assert_eq!(find("file.ts", "type T = Array<Array<T>>;", 3), Some(21));
+  }
+
+  #[test]
+  fn gets_line_and_column() {
+    assert_eq!(line_and_column("a\nbé c", 0), (1, 1));
+    assert_eq!(line_and_column("a\nbé c", 2), (2, 1));
+    assert_eq!(line_and_column("a\nbé c", 6), (2, 4));
+  }
+}
@@ -0,0 +1,59 @@
+use std::any::Any;
+
+use crate::format_text::FormatError;
+
+/// Version of Oxc the plugin was built with, read from Cargo.toml by build.rs.
+pub const OXC_VERSION: &str = env!("OXC_VERSION");
+
+/// Converts a panic while formatting into an error when the target supports
+/// unwinding, so a bug in Oxc only fails the one file.
+#[cfg(panic = "unwind")]
+pub fn catch_panic<T>(
+  file_description: &str,
+  action: impl FnOnce() -> Result<T, FormatError>,
+) -> Result<T, FormatError> {
+  match std::panic::catch_unwind(std::panic::AssertUnwindSafe(action)) {
+    Ok(result) => result,
+    Err(payload) => Err(panic_error_message(file_description, panic_payload_text(payload.as_ref())).into()),
+  }
+}
+
+#[cfg(not(panic = "unwind"))]
+pub fn catch_panic<T>(
+  _file_description: &str,
+  action: impl FnOnce() -> Result<T, FormatError>,
+) -> Result<T, FormatError> {
+  action()
+}
+
+#[cfg_attr(not(any(panic = "unwind", target_arch = "wasm32")), allow(dead_code))]
+pub fn panic_error_message(file_description: &str, message: &str) -> String {
+  format!(
+    "Oxc {} panicked while formatting {}. This is a bug in Oxc or the plugin.\n\n{}",
+    OXC_VERSION, file_description, message
+  )
+}
+
+#[cfg_attr(not(any(panic = "unwind", target_arch = "wasm32")), allow(dead_code))]
+pub fn panic_payload_text(payload: &(dyn Any + Send)) -> &str {
+  if let Some(text) = payload.downcast_ref::<&str>() {
+    text
+  } else if let Some(text) = payload.downcast_ref::<String>() {
+    text
+  } else {
+    "Unknown panic payload."
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[cfg(panic = "unwind")]
+  #[test]
+  fn converts_panics_to_errors() {
+    let err = catch_panic::<()>("file.ts", || panic!("oops")).unwrap_err();
+    assert_eq!(err.to_string(), panic_error_message("file.ts", "oops"));
+    assert!(err.to_string().contains(OXC_VERSION));
+  }
+}
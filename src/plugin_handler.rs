@@ -0,0 +1,241 @@
+//! Pieces shared by the Wasm and process plugin handlers.
+
+use dprint_core::configuration::ConfigKeyMap;
+use dprint_core::configuration::GlobalConfiguration;
+use dprint_core::plugins::CheckConfigUpdatesMessage;
+use dprint_core::plugins::ConfigChange;
+use dprint_core::plugins::ConfigChangeKind;
+use dprint_core::plugins::ConfigChangePathItem;
+use dprint_core::plugins::FileMatchingInfo;
+use dprint_core::plugins::PluginInfo;
+use dprint_core::plugins::PluginResolveConfigurationResult;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::Formatter;
+use crate::configuration::Configuration;
+use crate::configuration::resolve_config;
+use crate::format_cache::FormatCache;
+use crate::format_cache::hash_value;
+
+/// Legacy property names that `dprint config update` migrates to their
+/// current name (old name, new name).
+const RENAMED_KEYS: &[(&str, &str)] = &[("indentSize", "indentWidth"), ("trailingComma", "trailingCommas")];
+
+/// Memory budget of the cache of formatted files, which saves formatting
+/// files that editors send again without changes.
+const FORMAT_CACHE_MEMORY_BUDGET: usize = 16 * 1024 * 1024;
+
+/// Most formatters a plugin handler keeps. Override configurations (ex. for
+/// code blocks in Markdown) get their own formatter and dprint doesn't say
+/// when one is no longer used, so they're all dropped past this.
+const MAX_FORMATTERS: usize = 64;
+
+pub fn plugin_info() -> PluginInfo {
+  let version = env!("CARGO_PKG_VERSION").to_string();
+  PluginInfo {
+    name: env!("CARGO_PKG_NAME").to_string(),
+    version: version.clone(),
+    config_key: "oxc".to_string(),
+    help_url: "https://dprint.dev/plugins/oxc".to_string(),
+    config_schema_url: format!(
+      "https://plugins.dprint.dev/dprint/dprint-plugin-oxc/{}/schema.json",
+      version
+    ),
+    update_url: Some("https://plugins.dprint.dev/dprint/dprint-plugin-oxc/latest.json".to_string()),
+  }
+}
+
+pub fn license_text() -> String {
+  std::str::from_utf8(include_bytes!("../LICENSE")).unwrap().into()
+}
+
+pub fn resolve_plugin_config(
+  config: ConfigKeyMap,
+  global_config: &GlobalConfiguration,
+) -> PluginResolveConfigurationResult<Configuration> {
+  let result = resolve_config(config, global_config);
+  let mut file_extensions = vec![
+    "ts".to_string(),
+    "tsx".to_string(),
+    "cts".to_string(),
+    "mts".to_string(),
+    "js".to_string(),
+    "jsx".to_string(),
+    "cjs".to_string(),
+    "mjs".to_string(),
+  ];
+  for (extension, _) in &result.config.file_extensions {
+    if !file_extensions.contains(extension) {
+      file_extensions.push(extension.clone());
+    }
+  }
+  let file_names = result.config.file_names.iter().map(|(name, _)| name.clone()).collect();
+  PluginResolveConfigurationResult {
+    config: result.config,
+    diagnostics: result.diagnostics,
+    file_matching: FileMatchingInfo {
+      file_extensions,
+      file_names,
+    },
+  }
+}
+
+pub fn check_config_updates(message: &CheckConfigUpdatesMessage) -> Vec<ConfigChange> {
+  let mut changes = Vec::new();
+  for (old_key, new_key) in RENAMED_KEYS {
+    let Some(value) = message.config.get(*old_key) else {
+      continue;
+    };
+    // when both are specified the new key already wins in resolve_config,
+    // so only the old one needs to go
+    if !message.config.contains_key(*new_key) {
+      changes.push(ConfigChange {
+        path: vec![ConfigChangePathItem::String(new_key.to_string())],
+        kind: ConfigChangeKind::Add(value.clone()),
+      });
+    }
+    changes.push(ConfigChange {
+      path: vec![ConfigChangePathItem::String(old_key.to_string())],
+      kind: ConfigChangeKind::Remove,
+    });
+  }
+  changes
+}
+
+/// Creates the cache shared by the formatters of all the configurations.
+/// dprint doesn't say when a configuration is no longer used, so sharing it
+/// keeps the memory bounded as configurations are resolved again.
+pub fn create_format_cache() -> Arc<Mutex<FormatCache>> {
+  Arc::new(Mutex::new(FormatCache::new(FORMAT_CACHE_MEMORY_BUDGET)))
+}
+
+pub fn create_formatter(config: &Configuration, cache: &Arc<Mutex<FormatCache>>) -> Formatter {
+  let formatter = Formatter::new(config.clone());
+  if config.cache == Some(false) {
+    formatter
+  } else {
+    formatter.with_shared_cache(cache.clone())
+  }
+}
+
+/// Gets the formatter for a configuration, creating it when there isn't one.
+///
+/// Formatters are found by the contents of the configuration rather than its
+/// id because dprint keeps the id when a host override changes the
+/// configuration.
+pub fn get_or_create_formatter<'a, T>(
+  formatters: &'a mut HashMap<u64, T>,
+  config: &Configuration,
+  create: impl FnOnce() -> T,
+) -> &'a mut T {
+  let fingerprint = hash_value(config);
+  if formatters.len() >= MAX_FORMATTERS && !formatters.contains_key(&fingerprint) {
+    formatters.clear();
+  }
+  formatters.entry(fingerprint).or_insert_with(create)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn config_updates(config: serde_json::Value) -> Vec<String> {
+    let message = CheckConfigUpdatesMessage {
+      old_version: None,
+      config: serde_json::from_value(config).unwrap(),
+    };
+    check_config_updates(&message)
+      .into_iter()
+      .map(|change| {
+        let path = change
+          .path
+          .iter()
+          .map(|item| match item {
+            ConfigChangePathItem::String(name) => name.clone(),
+            ConfigChangePathItem::Number(index) => index.to_string(),
+          })
+          .collect::<Vec<_>>()
+          .join(".");
+        match change.kind {
+          ConfigChangeKind::Add(value) => format!("add {} {:?}", path, value),
+          ConfigChangeKind::Set(value) => format!("set {} {:?}", path, value),
+          ConfigChangeKind::Remove => format!("remove {}", path),
+        }
+      })
+      .collect()
+  }
+
+  #[test]
+  fn renames_legacy_keys() {
+    assert_eq!(
+      config_updates(serde_json::json!({ "indentSize": 4, "trailingComma": "none", "semi": false })),
+      [
+        "add indentWidth Number(4)",
+        "remove indentSize",
+        "add trailingCommas String(\"none\")",
+        "remove trailingComma",
+      ]
+    );
+  }
+
+  #[test]
+  fn removes_legacy_keys_when_the_new_key_exists() {
+    assert_eq!(
+      config_updates(serde_json::json!({ "indentSize": 4, "indentWidth": 2 })),
+      ["remove indentSize"]
+    );
+  }
+
+  #[test]
+  fn leaves_current_configs_unchanged() {
+    assert!(config_updates(serde_json::json!({ "indentWidth": 2, "trailingCommas": "all" })).is_empty());
+    assert!(config_updates(serde_json::json!({ "semi": false })).is_empty());
+  }
+
+  #[test]
+  fn shares_the_format_cache_unless_disabled() {
+    let cache = create_format_cache();
+    let path = std::path::Path::new("file.ts");
+    let first = create_formatter(&Configuration::default(), &cache);
+    let second = create_formatter(&Configuration::default(), &cache);
+    first.format(path, "let a=1").unwrap();
+    second.format(path, "let a=1").unwrap();
+    assert_eq!(second.cache_stats().unwrap().hits, 1);
+
+    let uncached = Configuration {
+      cache: Some(false),
+      ..Default::default()
+    };
+    assert!(create_formatter(&uncached, &cache).cache_stats().is_none());
+  }
+
+  #[test]
+  fn finds_formatters_by_config_contents() {
+    let mut formatters = HashMap::new();
+    let wide = Configuration {
+      line_width: Some(120),
+      ..Default::default()
+    };
+    assert_eq!(
+      *get_or_create_formatter(&mut formatters, &Configuration::default(), || 1),
+      1
+    );
+    assert_eq!(
+      *get_or_create_formatter(&mut formatters, &Configuration::default(), || 2),
+      1
+    );
+    assert_eq!(*get_or_create_formatter(&mut formatters, &wide, || 3), 3);
+
+    for line_width in 0..MAX_FORMATTERS as u16 {
+      let config = Configuration {
+        line_width: Some(line_width),
+        ..Default::default()
+      };
+      get_or_create_formatter(&mut formatters, &config, || 0);
+    }
+    assert!(formatters.len() <= MAX_FORMATTERS);
+  }
+}
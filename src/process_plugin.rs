@@ -0,0 +1,96 @@
+use dprint_core::async_runtime::LocalBoxFuture;
+use dprint_core::configuration::ConfigKeyMap;
+use dprint_core::configuration::GlobalConfiguration;
+use dprint_core::plugins::AsyncPluginHandler;
+use dprint_core::plugins::CheckConfigUpdatesMessage;
+use dprint_core::plugins::ConfigChange;
+use dprint_core::plugins::FormatError;
+use dprint_core::plugins::FormatRequest;
+use dprint_core::plugins::FormatResult;
+use dprint_core::plugins::HostFormatRequest;
+use dprint_core::plugins::PluginInfo;
+use dprint_core::plugins::PluginResolveConfigurationResult;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::Formatter;
+use crate::configuration::Configuration;
+use crate::format_cache::FormatCache;
+use crate::plugin_handler;
+
+/// How long formatting a file may take before it errors, which stops
+/// pathological inputs from tying up a thread.
+const FILE_TIME_BUDGET: Duration = Duration::from_secs(30);
+
+/// Serves the plugin over dprint's process plugin protocol (see the
+/// `dprint-plugin-oxc-process` binary), formatting files natively on a
+/// thread pool.
+pub struct OxcProcessPluginHandler {
+  /// Formatters by configuration fingerprint so the options are only built
+  /// once per configuration.
+  formatters: Mutex<HashMap<u64, Arc<Formatter>>>,
+  format_cache: Arc<Mutex<FormatCache>>,
+}
+
+impl Default for OxcProcessPluginHandler {
+  fn default() -> Self {
+    Self {
+      formatters: Mutex::new(HashMap::new()),
+      format_cache: plugin_handler::create_format_cache(),
+    }
+  }
+}
+
+impl AsyncPluginHandler for OxcProcessPluginHandler {
+  type Configuration = Configuration;
+
+  fn plugin_info(&self) -> PluginInfo {
+    plugin_handler::plugin_info()
+  }
+
+  fn license_text(&self) -> String {
+    plugin_handler::license_text()
+  }
+
+  async fn resolve_config(
+    &self,
+    config: ConfigKeyMap,
+    global_config: GlobalConfiguration,
+  ) -> PluginResolveConfigurationResult<Configuration> {
+    plugin_handler::resolve_plugin_config(config, &global_config)
+  }
+
+  async fn check_config_updates(&self, message: CheckConfigUpdatesMessage) -> Result<Vec<ConfigChange>, FormatError> {
+    Ok(plugin_handler::check_config_updates(&message))
+  }
+
+  async fn format(
+    &self,
+    request: FormatRequest<Configuration>,
+    _format_with_host: impl FnMut(HostFormatRequest) -> LocalBoxFuture<'static, FormatResult> + 'static,
+  ) -> FormatResult {
+    if request.range.is_some() {
+      return Ok(None); // not implemented
+    }
+
+    let formatter = self.get_formatter(&request.config);
+    // formatting is CPU bound, so keep it off the threads handling messages
+    let result = tokio::task::spawn_blocking(move || {
+      formatter.format_bytes_with_token(&request.file_path, &request.file_bytes, request.token.as_ref())
+    })
+    .await?;
+    Ok(result?)
+  }
+}
+
+impl OxcProcessPluginHandler {
+  fn get_formatter(&self, config: &Configuration) -> Arc<Formatter> {
+    let mut formatters = self.formatters.lock().unwrap();
+    plugin_handler::get_or_create_formatter(&mut formatters, config, || {
+      Arc::new(plugin_handler::create_formatter(config, &self.format_cache).with_time_budget(FILE_TIME_BUDGET))
+    })
+    .clone()
+  }
+}
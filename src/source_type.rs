@@ -0,0 +1,240 @@
+use oxc_span::SourceType;
+use std::path::Path;
+
+use crate::configuration::Configuration;
+use crate::configuration::JsxInJs;
+use crate::configuration::ModuleKind;
+use crate::configuration::SourceLanguage;
+use crate::configuration::SourceTypeAssociation;
+
+/// Gets the source type for a file path, checking the configured file names
+/// and extensions before falling back to the extensions Oxc knows about and,
+/// when enabled, the file's shebang.
+pub fn resolve_source_type(file_path: &Path, file_text: &str, config: &Configuration) -> Option<SourceType> {
+  let file_name = file_path.file_name()?.to_str()?;
+  if let Some((_, association)) = config.file_names.iter().find(|(name, _)| name == file_name) {
+    return Some(association_source_type(*association));
+  }
+
+  // prefer the most specific extension (ex. "d.mts" over "mts")
+  let lowercase_file_name = file_name.to_lowercase();
+  let extension_association = config
+    .file_extensions
+    .iter()
+    .filter(|(extension, _)| has_extension(&lowercase_file_name, extension))
+    .max_by_key(|(extension, _)| extension.len());
+  if let Some((_, association)) = extension_association {
+    return Some(association_source_type(*association));
+  }
+
+  match SourceType::from_path(file_path) {
+    Ok(source_type) => Some(apply_js_options(source_type, file_path, &lowercase_file_name, config)),
+    Err(_) if config.detect_shebang == Some(true) => shebang_source_type(file_text),
+    Err(_) => None,
+  }
+}
+
+/// Applies the options for the ambiguous `.js` and `.jsx` extensions.
+fn apply_js_options(
+  source_type: SourceType,
+  file_path: &Path,
+  lowercase_file_name: &str,
+  config: &Configuration,
+) -> SourceType {
+  if !has_extension(lowercase_file_name, "js") && !has_extension(lowercase_file_name, "jsx") {
+    return source_type;
+  }
+  let source_type = with_module_kind(source_type, config.js_module_kind);
+  if is_jsx_in_js(file_path, config) {
+    source_type.with_jsx(true)
+  } else {
+    source_type
+  }
+}
+
+/// Gets if the file is a `.js` file that the `jsxInJs` option applies to.
+pub fn is_jsx_in_js(file_path: &Path, config: &Configuration) -> bool {
+  let is_js = file_path
+    .extension()
+    .is_some_and(|extension| extension.eq_ignore_ascii_case("js"));
+  if !is_js {
+    return false;
+  }
+  match &config.jsx_in_js {
+    None | Some(JsxInJs::Bool(false)) => false,
+    Some(JsxInJs::Bool(true)) => true,
+    Some(JsxInJs::Globs(globs)) => globs.iter().any(|glob| matches_glob(glob, file_path)),
+  }
+}
+
+/// Matches a glob against a file path. dprint doesn't tell plugins where the
+/// configuration file is, so relative globs match the end of the path the
+/// same way in the Wasm and process plugins.
+fn matches_glob(glob: &str, file_path: &Path) -> bool {
+  if glob.starts_with('/') || glob.starts_with("**") {
+    return fast_glob::glob_match(glob, path_text(file_path));
+  }
+  let glob = glob.strip_prefix("./").unwrap_or(glob);
+  fast_glob::glob_match(format!("**/{}", glob), path_text(file_path))
+}
+
+fn path_text(path: &Path) -> String {
+  path.to_string_lossy().replace('\\', "/")
+}
+
+/// Gets the source type from the interpreter in a shebang such as
+/// `#!/usr/bin/env node` or `#!/usr/bin/env -S deno run`.
+fn shebang_source_type(file_text: &str) -> Option<SourceType> {
+  let line = file_text.strip_prefix("#!")?.lines().next()?;
+  let mut words = line.split_whitespace();
+  let mut interpreter = file_name(words.next()?);
+  if interpreter == "env" {
+    // skip env's flags (ex. `-S`) and environment variable assignments
+    interpreter = file_name(words.find(|word| !word.starts_with('-') && !word.contains('='))?);
+  }
+  match interpreter {
+    "node" | "nodejs" => Some(SourceType::unambiguous()),
+    "deno" | "ts-node" | "tsx" | "bun" => Some(SourceType::ts().with_unambiguous(true)),
+    _ => None,
+  }
+}
+
+fn file_name(path: &str) -> &str {
+  path.rsplit('/').next().unwrap_or(path)
+}
+
+fn has_extension(file_name: &str, extension: &str) -> bool {
+  file_name.len() > extension.len() + 1
+    && file_name.ends_with(extension)
+    && file_name.as_bytes()[file_name.len() - extension.len() - 1] == b'.'
+}
+
+fn association_source_type(association: SourceTypeAssociation) -> SourceType {
+  let source_type = match association.language {
+    SourceLanguage::Js => SourceType::unambiguous(),
+    SourceLanguage::Jsx => SourceType::unambiguous().with_jsx(true),
+    SourceLanguage::Ts => SourceType::ts().with_unambiguous(true),
+    SourceLanguage::Tsx => SourceType::tsx().with_unambiguous(true),
+    SourceLanguage::Dts => SourceType::d_ts(),
+  };
+  with_module_kind(source_type, association.module_kind)
+}
+
+fn with_module_kind(source_type: SourceType, module_kind: Option<ModuleKind>) -> SourceType {
+  match module_kind {
+    Some(ModuleKind::Module) => source_type.with_module(true),
+    Some(ModuleKind::Script) => source_type.with_script(true),
+    Some(ModuleKind::Unambiguous) => source_type.with_unambiguous(true),
+    Some(ModuleKind::CommonJs) => source_type.with_commonjs(true),
+    None => source_type,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn association(language: SourceLanguage, module_kind: Option<ModuleKind>) -> SourceTypeAssociation {
+    SourceTypeAssociation { language, module_kind }
+  }
+
+  #[test]
+  fn uses_configured_associations() {
+    let config = Configuration {
+      file_extensions: vec![
+        ("es6".to_string(), association(SourceLanguage::Js, None)),
+        (
+          "pac".to_string(),
+          association(SourceLanguage::Js, Some(ModuleKind::Script)),
+        ),
+        (
+          "d.mts".to_string(),
+          association(SourceLanguage::Dts, Some(ModuleKind::Module)),
+        ),
+      ],
+      file_names: vec![("Jakefile".to_string(), association(SourceLanguage::Js, None))],
+      ..Default::default()
+    };
+
+    let source_type = resolve_source_type(Path::new("src/file.ES6"), "", &config).unwrap();
+    assert!(source_type.is_javascript() && !source_type.is_jsx());
+    let source_type = resolve_source_type(Path::new("proxy.pac"), "", &config).unwrap();
+    assert!(source_type.is_script());
+    let source_type = resolve_source_type(Path::new("types.d.mts"), "", &config).unwrap();
+    assert!(source_type.is_typescript_definition() && source_type.is_module());
+    assert!(resolve_source_type(Path::new("Jakefile"), "", &config).is_some());
+    assert!(resolve_source_type(Path::new("jakefile"), "", &config).is_none());
+    assert!(resolve_source_type(Path::new("es6"), "", &config).is_none());
+    let source_type = resolve_source_type(Path::new("file.tsx"), "", &config).unwrap();
+    assert!(source_type.is_typescript() && source_type.is_jsx());
+  }
+
+  #[test]
+  fn detects_shebangs_when_enabled() {
+    let config = Configuration {
+      detect_shebang: Some(true),
+      ..Default::default()
+    };
+    let path = Path::new("bin/tool");
+
+    let source_type = resolve_source_type(path, "#!/usr/bin/env node\nconsole.log(1);\n", &config).unwrap();
+    assert!(source_type.is_javascript());
+    let source_type = resolve_source_type(path, "#!/usr/bin/env -S deno run --allow-read\n", &config).unwrap();
+    assert!(source_type.is_typescript());
+    let source_type = resolve_source_type(path, "#!/usr/bin/env -S NODE_ENV=production tsx\n", &config).unwrap();
+    assert!(source_type.is_typescript());
+    assert!(resolve_source_type(path, "#!/usr/local/bin/node\n", &config).is_some());
+    assert!(resolve_source_type(path, "#!/bin/sh\n", &config).is_none());
+    assert!(resolve_source_type(path, "console.log(1);\n", &config).is_none());
+    assert!(resolve_source_type(path, "#!/usr/bin/env node\n", &Configuration::default()).is_none());
+  }
+
+  #[test]
+  fn applies_js_options_to_ambiguous_extensions() {
+    let config = Configuration {
+      js_module_kind: Some(ModuleKind::Script),
+      jsx_in_js: Some(JsxInJs::Bool(true)),
+      ..Default::default()
+    };
+
+    let source_type = resolve_source_type(Path::new("file.js"), "", &config).unwrap();
+    assert!(source_type.is_script() && source_type.is_jsx());
+    let source_type = resolve_source_type(Path::new("file.jsx"), "", &config).unwrap();
+    assert!(source_type.is_script());
+    let source_type = resolve_source_type(Path::new("file.mjs"), "", &config).unwrap();
+    assert!(source_type.is_module() && !source_type.is_jsx());
+    let source_type = resolve_source_type(Path::new("file.ts"), "", &config).unwrap();
+    assert!(source_type.is_unambiguous());
+  }
+
+  #[test]
+  fn matches_jsx_in_js_globs() {
+    let config = Configuration {
+      jsx_in_js: Some(JsxInJs::Globs(vec![
+        "src/components/**".to_string(),
+        "/repo/app.js".to_string(),
+      ])),
+      ..Default::default()
+    };
+
+    assert!(is_jsx_in_js(Path::new("/repo/src/components/Button.js"), &config));
+    assert!(is_jsx_in_js(Path::new("/repo/app.js"), &config));
+    assert!(!is_jsx_in_js(Path::new("/repo/src/components/Button.mjs"), &config));
+    assert!(!is_jsx_in_js(Path::new("/repo/src/utils/math.js"), &config));
+    assert!(!is_jsx_in_js(Path::new("/other/repo/app.js"), &config));
+  }
+
+  #[test]
+  fn matches_relative_jsx_in_js_globs_against_the_end_of_the_path() {
+    let config = Configuration {
+      jsx_in_js: Some(JsxInJs::Globs(vec!["src/*.js".to_string(), "./lib/**".to_string()])),
+      ..Default::default()
+    };
+
+    assert!(is_jsx_in_js(Path::new("/repo/src/App.js"), &config));
+    assert!(is_jsx_in_js(Path::new("/repo/other/src/App.js"), &config));
+    assert!(is_jsx_in_js(Path::new("/repo/lib/nested/View.js"), &config));
+    assert!(!is_jsx_in_js(Path::new("/repo/src/nested/App.js"), &config));
+    assert!(!is_jsx_in_js(Path::new("/repo/source/App.js"), &config));
+  }
+}
@@ -0,0 +1,132 @@
+use std::iter;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::configuration::Configuration;
+use crate::format_text::FormatError;
+use crate::formatter::Formatter;
+use crate::token_alignment::align_tokens;
+
+/// A replacement of a range of the original text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+  /// Byte range in the original text.
+  pub range: Range<usize>,
+  pub new_text: String,
+}
+
+/// Formats the text of a file, returning the edits that turn it into the
+/// formatted text. There are no edits when the file is already formatted or
+/// not supported.
+pub fn format_text_edits(
+  file_path: &Path,
+  input_text: &str,
+  config: &Configuration,
+) -> Result<Vec<TextEdit>, FormatError> {
+  Formatter::new(config.clone()).format_edits(file_path, input_text)
+}
+
+/// Gets a minimal list of edits that turn the old text into the new text,
+/// sorted by position and not overlapping.
+///
+/// Matching tokens are aligned first, so the edits are the changes between
+/// them (usually whitespace and punctuation) rather than a line based diff.
+pub fn compute_text_edits(old_text: &str, new_text: &str) -> Vec<TextEdit> {
+  let mut edits = Vec::new();
+  let mut old_end = 0;
+  let mut new_end = 0;
+  let text_ends = (old_text.len()..old_text.len(), new_text.len()..new_text.len());
+  for (old_range, new_range) in align_tokens(old_text, new_text)
+    .into_iter()
+    .chain(iter::once(text_ends))
+  {
+    let old_gap = old_end..old_range.start;
+    let new_gap = &new_text[new_end..new_range.start];
+    if old_text[old_gap.clone()] != *new_gap {
+      edits.push(trimmed_edit(old_text, old_gap, new_gap));
+    }
+    old_end = old_range.end;
+    new_end = new_range.end;
+  }
+  edits
+}
+
+/// Creates an edit that leaves out the text the old and new text start and
+/// end with.
+fn trimmed_edit(old_text: &str, old_range: Range<usize>, new_text: &str) -> TextEdit {
+  let old = &old_text[old_range.clone()];
+  let prefix_len = common_prefix_len(old.chars(), new_text.chars());
+  let suffix_len = common_prefix_len(old[prefix_len..].chars().rev(), new_text[prefix_len..].chars().rev());
+  TextEdit {
+    range: old_range.start + prefix_len..old_range.end - suffix_len,
+    new_text: new_text[prefix_len..new_text.len() - suffix_len].to_string(),
+  }
+}
+
+/// Gets the length in bytes of the characters the two iterators start with.
+fn common_prefix_len(a: impl Iterator<Item = char>, b: impl Iterator<Item = char>) -> usize {
+  a.zip(b).take_while(|(a, b)| a == b).map(|(c, _)| c.len_utf8()).sum()
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn apply_edits(text: &str, edits: &[TextEdit]) -> String {
+    let mut result = text.to_string();
+    for edit in edits.iter().rev() {
+      result.replace_range(edit.range.clone(), &edit.new_text);
+    }
+    result
+  }
+
+  fn edit(range: Range<usize>, new_text: &str) -> TextEdit {
+    TextEdit {
+      range,
+      new_text: new_text.to_string(),
+    }
+  }
+
+  #[test]
+  fn computes_minimal_edits() {
+    assert_eq!(
+      compute_text_edits("const x=1", "const x = 1;\n"),
+      [edit(7..7, " "), edit(8..8, " "), edit(9..9, ";\n")]
+    );
+    assert_eq!(
+      compute_text_edits("f('a',  é)", "f(\"a\", é);"),
+      [edit(2..3, "\""), edit(4..5, "\""), edit(7..8, ""), edit(11..11, ";")]
+    );
+    assert!(compute_text_edits("same", "same").is_empty());
+  }
+
+  #[test]
+  fn edits_apply_to_the_new_text() {
+    let cases = [
+      (
+        "function f(a,b){return a+b}",
+        "function f(a, b) {\n  return a + b;\n}\n",
+      ),
+      (
+        "import {b,a} from 'x'\nlet y = 1",
+        "import { a, b } from \"x\";\nlet y = 1;\n",
+      ),
+      ("<div>  text </div>", "<div> text </div>;\n"),
+      ("", "\n"),
+      ("a\n", ""),
+    ];
+    for (old_text, new_text) in cases {
+      let edits = compute_text_edits(old_text, new_text);
+      assert_eq!(apply_edits(old_text, &edits), new_text);
+      assert!(edits.windows(2).all(|pair| pair[0].range.end <= pair[1].range.start));
+    }
+  }
+
+  #[test]
+  fn formats_to_edits() {
+    let edits = format_text_edits(Path::new("file.ts"), "let a=1", &Configuration::default()).unwrap();
+    assert_eq!(apply_edits("let a=1", &edits), "let a = 1;\n");
+    let edits = format_text_edits(Path::new("file.ts"), "let a = 1;\n", &Configuration::default()).unwrap();
+    assert!(edits.is_empty());
+  }
+}
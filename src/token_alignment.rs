@@ -0,0 +1,214 @@
+use std::ops::Range;
+
+/// Differences beyond this many tokens aren't aligned, which bounds the time
+/// and memory used on inputs that changed almost entirely.
+const MAX_EDIT_DISTANCE: usize = 2_000;
+
+/// Finds the tokens that are the same in the old and new text, returned as
+/// pairs of their byte ranges in order.
+///
+/// Tokens are words (identifiers, keywords and numbers) and individual
+/// punctuation characters, so whitespace and quotes changed by formatting
+/// fall between matched tokens. The tokens are aligned with Myers' diff
+/// algorithm after skipping the common prefix and suffix.
+pub fn align_tokens(old_text: &str, new_text: &str) -> Vec<(Range<usize>, Range<usize>)> {
+  let old_tokens = tokenize(old_text);
+  let new_tokens = tokenize(new_text);
+  let is_same = |old: &Range<usize>, new: &Range<usize>| old_text[old.clone()] == new_text[new.clone()];
+
+  let prefix_len = old_tokens
+    .iter()
+    .zip(&new_tokens)
+    .take_while(|(old, new)| is_same(old, new))
+    .count();
+  let suffix_len = old_tokens[prefix_len..]
+    .iter()
+    .rev()
+    .zip(new_tokens[prefix_len..].iter().rev())
+    .take_while(|(old, new)| is_same(old, new))
+    .count();
+  let old_middle = &old_tokens[prefix_len..old_tokens.len() - suffix_len];
+  let new_middle = &new_tokens[prefix_len..new_tokens.len() - suffix_len];
+  let old_words = old_middle
+    .iter()
+    .map(|range| &old_text[range.clone()])
+    .collect::<Vec<_>>();
+  let new_words = new_middle
+    .iter()
+    .map(|range| &new_text[range.clone()])
+    .collect::<Vec<_>>();
+  // when the texts differ too much, the middle is left unaligned
+  let middle_matches = myers_matches(&old_words, &new_words, MAX_EDIT_DISTANCE).unwrap_or_default();
+
+  let mut matches = Vec::with_capacity(prefix_len + middle_matches.len() + suffix_len);
+  matches.extend(
+    old_tokens[..prefix_len]
+      .iter()
+      .cloned()
+      .zip(new_tokens[..prefix_len].iter().cloned()),
+  );
+  matches.extend(
+    middle_matches
+      .into_iter()
+      .map(|(old_index, new_index)| (old_middle[old_index].clone(), new_middle[new_index].clone())),
+  );
+  matches.extend(
+    old_tokens[old_tokens.len() - suffix_len..]
+      .iter()
+      .cloned()
+      .zip(new_tokens[new_tokens.len() - suffix_len..].iter().cloned()),
+  );
+  matches
+}
+
+/// Splits text into words and punctuation characters, skipping whitespace.
+fn tokenize(text: &str) -> Vec<Range<usize>> {
+  let is_word_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$' || !c.is_ascii();
+  let mut tokens = Vec::new();
+  let mut word_start = None;
+  for (index, c) in text.char_indices() {
+    if is_word_char(c) && !c.is_whitespace() {
+      word_start.get_or_insert(index);
+      continue;
+    }
+    if let Some(start) = word_start.take() {
+      tokens.push(start..index);
+    }
+    if !c.is_whitespace() {
+      tokens.push(index..index + c.len_utf8());
+    }
+  }
+  if let Some(start) = word_start {
+    tokens.push(start..text.len());
+  }
+  tokens
+}
+
+/// Gets the index pairs of the items in a longest common subsequence of `a`
+/// and `b`, or `None` when more than `max_distance` insertions and deletions
+/// are needed.
+fn myers_matches<T: PartialEq>(a: &[T], b: &[T], max_distance: usize) -> Option<Vec<(usize, usize)>> {
+  let (n, m) = (a.len() as isize, b.len() as isize);
+  let max_d = (a.len() + b.len()).min(max_distance) as isize;
+  // the furthest x reached on each diagonal k = x - y, offset to be an index
+  let offset = max_d + 1;
+  let mut v = vec![0isize; 2 * max_d as usize + 3];
+  // the diagonals around each step before it was taken, for backtracking
+  let mut trace: Vec<Vec<isize>> = Vec::new();
+
+  for d in 0..=max_d {
+    trace.push(v[(offset - d - 1) as usize..=(offset + d + 1) as usize].to_vec());
+    for k in (-d..=d).step_by(2) {
+      let index = (offset + k) as usize;
+      let mut x = if k == -d || (k != d && v[index - 1] < v[index + 1]) {
+        v[index + 1]
+      } else {
+        v[index - 1] + 1
+      };
+      let mut y = x - k;
+      while x < n && y < m && a[x as usize] == b[y as usize] {
+        x += 1;
+        y += 1;
+      }
+      v[index] = x;
+      if x >= n && y >= m {
+        return Some(backtrack(&trace, n, m));
+      }
+    }
+  }
+  None
+}
+
+fn backtrack(trace: &[Vec<isize>], n: isize, m: isize) -> Vec<(usize, usize)> {
+  let mut matches = Vec::new();
+  let (mut x, mut y) = (n, m);
+  for (d, v) in trace.iter().enumerate().rev() {
+    let d = d as isize;
+    // `v` holds the diagonals from -d - 1 to d + 1
+    let get = |k: isize| v[(k + d + 1) as usize];
+    let k = x - y;
+    let prev_k = if k == -d || (k != d && get(k - 1) < get(k + 1)) {
+      k + 1
+    } else {
+      k - 1
+    };
+    let prev_x = get(prev_k);
+    let prev_y = prev_x - prev_k;
+    while x > prev_x && y > prev_y {
+      x -= 1;
+      y -= 1;
+      matches.push((x as usize, y as usize));
+    }
+    x = prev_x;
+    y = prev_y;
+  }
+  matches.reverse();
+  matches
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn aligned_words<'a>(old_text: &'a str, new_text: &'a str) -> Vec<(&'a str, &'a str)> {
+    align_tokens(old_text, new_text)
+      .into_iter()
+      .map(|(old, new)| (&old_text[old], &new_text[new]))
+      .collect()
+  }
+
+  #[test]
+  fn tokenizes_words_and_punctuation() {
+    let text = "const café=a.b_c ( 'x y' );";
+    let tokens = tokenize(text).into_iter().map(|range| &text[range]).collect::<Vec<_>>();
+    assert_eq!(
+      tokens,
+      ["const", "café", "=", "a", ".", "b_c", "(", "'", "x", "y", "'", ")", ";"]
+    );
+  }
+
+  #[test]
+  fn aligns_matching_tokens() {
+    assert_eq!(
+      aligned_words("let a=[1,2]", "let a = [1, 2];\n"),
+      [
+        ("let", "let"),
+        ("a", "a"),
+        ("=", "="),
+        ("[", "["),
+        ("1", "1"),
+        (",", ","),
+        ("2", "2"),
+        ("]", "]")
+      ]
+    );
+    assert_eq!(
+      aligned_words("f(a, b)\ng()", "f(\n  b,\n  a,\n);\ng();"),
+      [
+        ("f", "f"),
+        ("(", "("),
+        ("a", "a"),
+        (",", ","),
+        (")", ")"),
+        ("g", "g"),
+        ("(", "("),
+        (")", ")")
+      ]
+    );
+  }
+
+  #[test]
+  fn finds_a_longest_common_subsequence() {
+    let a = "abcabba".chars().collect::<Vec<_>>();
+    let b = "cbabac".chars().collect::<Vec<_>>();
+    let matches = myers_matches(&a, &b, 100).unwrap();
+    assert_eq!(matches.len(), 4);
+    assert!(matches.iter().all(|(i, j)| a[*i] == b[*j]));
+    assert!(
+      matches
+        .windows(2)
+        .all(|pair| pair[0].0 < pair[1].0 && pair[0].1 < pair[1].1)
+    );
+    assert_eq!(myers_matches(&a, &b, 2), None);
+  }
+}
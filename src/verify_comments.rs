@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use oxc_ast::ast::Program;
+
+/// A comment that was lost, duplicated, or added by formatting.
+pub enum CommentDifference {
+  Missing { text: String, input_offset: usize },
+  Duplicated { text: String, input_offset: usize },
+  Added { text: String },
+}
+
+/// Compares the comments of the input and the formatted output regardless of
+/// where they are, since the formatter may move comments.
+///
+/// JSDoc comments are reformatted when `jsdoc` is enabled, so only their
+/// number is compared in that case.
+pub fn find_comment_difference(
+  input_text: &str,
+  input: &Program,
+  output_text: &str,
+  output: &Program,
+  is_jsdoc_enabled: bool,
+) -> Option<CommentDifference> {
+  let input_comments = collect_comments(input_text, input, is_jsdoc_enabled);
+  let output_comments = collect_comments(output_text, output, is_jsdoc_enabled);
+
+  let mut output_counts = HashMap::<&str, usize>::new();
+  for (text, _) in &output_comments {
+    *output_counts.entry(text.as_str()).or_default() += 1;
+  }
+  let mut input_counts = HashMap::<&str, usize>::new();
+  for (text, input_offset) in &input_comments {
+    let input_count = input_counts.entry(text.as_str()).or_default();
+    *input_count += 1;
+    if *input_count > output_counts.get(text.as_str()).copied().unwrap_or(0) {
+      return Some(CommentDifference::Missing {
+        text: text.clone(),
+        input_offset: *input_offset,
+      });
+    }
+  }
+
+  let (text, _) = output_comments
+    .iter()
+    .find(|(text, _)| output_counts[text.as_str()] > input_counts.get(text.as_str()).copied().unwrap_or(0))?;
+  match input_comments.iter().find(|(input_text, _)| input_text == text) {
+    Some((text, input_offset)) => Some(CommentDifference::Duplicated {
+      text: text.clone(),
+      input_offset: *input_offset,
+    }),
+    None => Some(CommentDifference::Added { text: text.clone() }),
+  }
+}
+
+/// Gets the normalized text of each comment along with its byte offset.
+fn collect_comments(text: &str, program: &Program, is_jsdoc_enabled: bool) -> Vec<(String, usize)> {
+  program
+    .comments
+    .iter()
+    .map(|comment| {
+      let comment_text = &text[comment.span.start as usize..comment.span.end as usize];
+      let normalized = if comment.is_line() {
+        comment_text.trim_end().to_string()
+      } else if is_jsdoc_enabled && comment_text.starts_with("/**") && comment_text != "/**/" {
+        "/** (JSDoc) */".to_string()
+      } else {
+        // block comments are re-indented
+        comment_text.lines().map(str::trim).collect::<Vec<_>>().join("\n")
+      };
+      (normalized, comment.span.start as usize)
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+  use oxc_allocator::Allocator;
+  use oxc_parser::Parser;
+  use oxc_span::SourceType;
+
+  use super::*;
+
+  fn difference(input: &str, output: &str, is_jsdoc_enabled: bool) -> Option<String> {
+    let allocator = Allocator::default();
+    let input_program = Parser::new(&allocator, input, SourceType::ts()).parse().program;
+    let output_program = Parser::new(&allocator, output, SourceType::ts()).parse().program;
+    find_comment_difference(input, &input_program, output, &output_program, is_jsdoc_enabled).map(|difference| {
+      match difference {
+        CommentDifference::Missing { text, input_offset } => format!("missing {} at {}", text, input_offset),
+        CommentDifference::Duplicated { text, input_offset } => format!("duplicated {} at {}", text, input_offset),
+        CommentDifference::Added { text } => format!("added {}", text),
+      }
+    })
+  }
+
+  #[test]
+  fn finds_lost_and_duplicated_comments() {
+    let input = "a(); // one\n  /*\n   * two\n   */\nb();\n";
+    assert_eq!(difference(input, "/*\n * two\n */\na(); // one\nb();\n", false), None);
+    assert_eq!(
+      difference(input, "a(); // one\nb();\n", false).unwrap(),
+      "missing /*\n* two\n*/ at 14"
+    );
+    assert_eq!(
+      difference(input, "a(); // one\n/*\n * two\n */\nb(); // one\n", false).unwrap(),
+      "duplicated // one at 5"
+    );
+    assert_eq!(difference("a();\n", "a(); // one\n", false).unwrap(), "added // one");
+  }
+
+  #[test]
+  fn only_counts_jsdoc_comments_when_reformatted() {
+    let input = "/** does a thing */\nfunction a() {}\n";
+    let output = "/** Does a thing. */\nfunction a() {}\n";
+    assert!(difference(input, output, false).is_some());
+    assert_eq!(difference(input, output, true), None);
+    assert!(difference(input, "function a() {}\n", true).is_some());
+  }
+}
@@ -0,0 +1,52 @@
+/// Describes the lines that changed when formatting the output of the first
+/// pass again, in the style of a unified diff.
+pub fn unstable_region_diff(first_pass: &str, second_pass: &str) -> String {
+  let first_lines = first_pass.split('\n').collect::<Vec<_>>();
+  let second_lines = second_pass.split('\n').collect::<Vec<_>>();
+  let common_prefix = first_lines
+    .iter()
+    .zip(&second_lines)
+    .take_while(|(first, second)| first == second)
+    .count();
+  let max_common_suffix = first_lines.len().min(second_lines.len()) - common_prefix;
+  let common_suffix = first_lines
+    .iter()
+    .rev()
+    .zip(second_lines.iter().rev())
+    .take(max_common_suffix)
+    .take_while(|(first, second)| first == second)
+    .count();
+
+  let first_changed = &first_lines[common_prefix..first_lines.len() - common_suffix];
+  let second_changed = &second_lines[common_prefix..second_lines.len() - common_suffix];
+  let mut text = format!(
+    "@@ -{},{} +{},{} @@",
+    common_prefix + 1,
+    first_changed.len(),
+    common_prefix + 1,
+    second_changed.len()
+  );
+  for line in first_changed {
+    text.push_str("\n-");
+    text.push_str(line);
+  }
+  for line in second_changed {
+    text.push_str("\n+");
+    text.push_str(line);
+  }
+  text
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn describes_the_unstable_region() {
+    assert_eq!(
+      unstable_region_diff("a;\nb(1);\nc;\n", "a;\nb(\n  1,\n);\nc;\n"),
+      "@@ -2,1 +2,3 @@\n-b(1);\n+b(\n+  1,\n+);"
+    );
+    assert_eq!(unstable_region_diff("a;\n", "a;\n\n"), "@@ -3,0 +3,1 @@\n+");
+  }
+}
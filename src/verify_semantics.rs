@@ -0,0 +1,501 @@
+use oxc_ast::AstKind;
+use oxc_ast::ast::JSXExpression;
+use oxc_ast::ast::Program;
+use oxc_ast::ast::PropertyKey;
+use oxc_ast_visit::Visit;
+use oxc_ast_visit::walk;
+use oxc_span::GetSpan;
+
+use crate::configuration::Configuration;
+
+/// The first node that differs between the input and the formatted output.
+pub struct SemanticDifference {
+  /// Byte offset of the node in the input, or the end of the input when the
+  /// output has extra nodes.
+  pub input_offset: usize,
+  pub expected: String,
+  pub found: String,
+}
+
+/// Compares the syntax trees of the input and the formatted output, ignoring
+/// spans, parentheses, and trivia along with the changes the formatter makes
+/// on purpose (ex. quoting property keys or sorting imports).
+pub fn find_semantic_difference(
+  input: &Program,
+  output: &Program,
+  config: &Configuration,
+) -> Option<SemanticDifference> {
+  let input_nodes = collect_nodes(input, config);
+  let output_nodes = collect_nodes(output, config);
+
+  let difference = input_nodes
+    .nodes
+    .iter()
+    .map(Some)
+    .chain(std::iter::repeat(None))
+    .zip(output_nodes.nodes.iter().map(Some).chain(std::iter::repeat(None)))
+    .take(input_nodes.nodes.len().max(output_nodes.nodes.len()))
+    .find(|(input, output)| input.map(|node| &node.signature) != output.map(|node| &node.signature));
+  if let Some((input_node, output_node)) = difference {
+    return Some(SemanticDifference {
+      input_offset: input_node
+        .map(|node| node.start as usize)
+        .unwrap_or(input.span.end as usize),
+      expected: signature_text(input_node),
+      found: signature_text(output_node),
+    });
+  }
+
+  // sorted imports are compared regardless of their order
+  let mut input_imports = input_nodes.imports;
+  let mut output_imports = output_nodes.imports;
+  input_imports.sort_by(|a, b| a.signature.cmp(&b.signature));
+  output_imports.sort_by(|a, b| a.signature.cmp(&b.signature));
+  input_imports
+    .iter()
+    .zip(&output_imports)
+    .find(|(input, output)| input.signature != output.signature)
+    .map(|(input, output)| SemanticDifference {
+      input_offset: input.start as usize,
+      expected: input.signature.clone(),
+      found: output.signature.clone(),
+    })
+}
+
+fn signature_text(node: Option<&Node>) -> String {
+  node
+    .map(|node| node.signature.clone())
+    .unwrap_or_else(|| "the end of the file".to_string())
+}
+
+struct Node {
+  signature: String,
+  start: u32,
+  is_jsx_text: bool,
+}
+
+struct CollectedNodes {
+  nodes: Vec<Node>,
+  /// Import declarations when imports are sorted, each as a single node.
+  imports: Vec<Node>,
+}
+
+fn collect_nodes(program: &Program, config: &Configuration) -> CollectedNodes {
+  let mut collector = NodeCollector {
+    sort_imports: config.experimental_sort_imports.is_some(),
+    sort_class_names: config.experimental_tailwindcss.is_some(),
+    nodes: Vec::new(),
+    imports: Vec::new(),
+    depth: 0,
+    skip_below_depth: None,
+    import_start: None,
+  };
+  collector.visit_program(program);
+  for node in &mut collector.nodes {
+    if node.is_jsx_text {
+      node.signature = format!("JSXText({})", collapse_whitespace(&node.signature));
+    }
+  }
+  CollectedNodes {
+    nodes: collector.nodes,
+    imports: collector.imports,
+  }
+}
+
+struct NodeCollector {
+  sort_imports: bool,
+  sort_class_names: bool,
+  nodes: Vec<Node>,
+  imports: Vec<Node>,
+  depth: u32,
+  /// Depth of a node whose descendants are described by the node itself.
+  skip_below_depth: Option<u32>,
+  /// Index in `nodes` of the import declaration being visited.
+  import_start: Option<usize>,
+}
+
+impl NodeCollector {
+  fn push(&mut self, signature: String, start: u32) {
+    self.nodes.push(Node {
+      signature,
+      start,
+      is_jsx_text: false,
+    });
+  }
+
+  /// Adds JSX text, merging it with adjacent text because the formatter
+  /// moves whitespace between text and `{" "}` when wrapping lines.
+  fn push_jsx_text(&mut self, text: &str, start: u32) {
+    if text.is_empty() {
+      return;
+    }
+    match self.nodes.last_mut() {
+      Some(node) if node.is_jsx_text => node.signature.push_str(text),
+      _ => self.nodes.push(Node {
+        signature: text.to_string(),
+        start,
+        is_jsx_text: true,
+      }),
+    }
+  }
+
+  fn node_signature(&self, kind: AstKind) -> String {
+    match kind {
+      AstKind::BinaryExpression(expr) => format!("BinaryExpression({})", expr.operator.as_str()),
+      AstKind::LogicalExpression(expr) => format!("LogicalExpression({})", expr.operator.as_str()),
+      AstKind::AssignmentExpression(expr) => format!("AssignmentExpression({})", expr.operator.as_str()),
+      AstKind::UnaryExpression(expr) => format!("UnaryExpression({})", expr.operator.as_str()),
+      AstKind::UpdateExpression(expr) => format!(
+        "UpdateExpression({}{})",
+        if expr.prefix { "prefix " } else { "" },
+        expr.operator.as_str()
+      ),
+      AstKind::VariableDeclaration(decl) => format!("VariableDeclaration({})", decl.kind.as_str()),
+      AstKind::Function(function) => with_modifiers(
+        &kind.debug_name(),
+        &[("async", function.r#async), ("generator", function.generator)],
+      ),
+      AstKind::ArrowFunctionExpression(arrow) => with_modifiers(&kind.debug_name(), &[("async", arrow.r#async)]),
+      AstKind::MethodDefinition(method) => with_modifiers(
+        &format!("MethodDefinition({:?})", method.kind),
+        &[("static", method.r#static)],
+      ),
+      AstKind::PropertyDefinition(property) => with_modifiers(&kind.debug_name(), &[("static", property.r#static)]),
+      AstKind::AccessorProperty(property) => with_modifiers(&kind.debug_name(), &[("static", property.r#static)]),
+      AstKind::ObjectProperty(property) => format!("{} {:?}", kind.debug_name(), property.kind),
+      AstKind::ForOfStatement(statement) => with_modifiers(&kind.debug_name(), &[("await", statement.r#await)]),
+      AstKind::TemplateElement(element) => format!("TemplateElement({})", element.value.raw),
+      AstKind::TSTypeOperator(operator) => format!("TSTypeOperator({})", operator.operator.to_str()),
+      AstKind::ImportDeclaration(decl) => with_modifiers(&kind.debug_name(), &[("type", decl.import_kind.is_type())]),
+      AstKind::ImportSpecifier(specifier) => {
+        with_modifiers(&kind.debug_name(), &[("type", specifier.import_kind.is_type())])
+      }
+      AstKind::ExportNamedDeclaration(decl) => {
+        with_modifiers(&kind.debug_name(), &[("type", decl.export_kind.is_type())])
+      }
+      AstKind::ExportFromDeclaration(decl) => {
+        with_modifiers(&kind.debug_name(), &[("type", decl.export_kind.is_type())])
+      }
+      AstKind::ExportAllDeclaration(decl) => {
+        with_modifiers(&kind.debug_name(), &[("type", decl.export_kind.is_type())])
+      }
+      AstKind::ExportSpecifier(specifier) => {
+        with_modifiers(&kind.debug_name(), &[("type", specifier.export_kind.is_type())])
+      }
+      // class names are sorted and their whitespace collapsed
+      AstKind::StringLiteral(literal) if self.sort_class_names => {
+        let mut class_names = literal.value.split_whitespace().collect::<Vec<_>>();
+        class_names.sort_unstable();
+        format!("StringLiteral({})", class_names.join(" "))
+      }
+      _ => kind.debug_name().into_owned(),
+    }
+  }
+}
+
+impl<'a> Visit<'a> for NodeCollector {
+  fn enter_node(&mut self, kind: AstKind<'a>) {
+    self.depth += 1;
+    if self.skip_below_depth.is_some() {
+      return;
+    }
+    // whitespace may be moved between `{" "}` and the surrounding text
+    if let AstKind::JSXExpressionContainer(container) = kind
+      && let JSXExpression::StringLiteral(literal) = &container.expression
+      && literal.value.trim().is_empty()
+    {
+      self.push_jsx_text(&literal.value, container.span.start);
+      self.skip_below_depth = Some(self.depth);
+      return;
+    }
+
+    match kind {
+      // empty statements are removed
+      AstKind::EmptyStatement(_) => {}
+      AstKind::JSXText(text) => self.push_jsx_text(&jsx_text_value(&text.value), text.span.start),
+      AstKind::ImportDeclaration(_) if self.sort_imports && self.import_start.is_none() => {
+        self.import_start = Some(self.nodes.len());
+        self.push(self.node_signature(kind), kind.span().start);
+      }
+      _ => self.push(self.node_signature(kind), kind.span().start),
+    }
+  }
+
+  fn leave_node(&mut self, kind: AstKind<'a>) {
+    if self.skip_below_depth == Some(self.depth) {
+      self.skip_below_depth = None;
+    }
+    self.depth -= 1;
+
+    if let AstKind::ImportDeclaration(_) = kind
+      && let Some(import_start) = self.import_start.take()
+    {
+      let mut signatures = self
+        .nodes
+        .drain(import_start..)
+        .map(|node| node.signature)
+        .collect::<Vec<_>>();
+      // the specifiers may be sorted too, so only their contents are compared
+      signatures[1..].sort_unstable();
+      self.imports.push(Node {
+        signature: signatures.join(" "),
+        start: kind.span().start,
+        is_jsx_text: false,
+      });
+      // keeps the position of the import so added or removed imports are found
+      self.push("ImportDeclaration".to_string(), kind.span().start);
+    }
+  }
+
+  // quotes around property keys are added and removed
+  fn visit_property_key(&mut self, it: &PropertyKey<'a>) {
+    if self.skip_below_depth.is_none()
+      && matches!(
+        it,
+        PropertyKey::StaticIdentifier(_) | PropertyKey::StringLiteral(_) | PropertyKey::NumericLiteral(_)
+      )
+      && let Some(name) = it.static_name()
+    {
+      self.push(format!("PropertyKey({})", name), it.span().start);
+      return;
+    }
+    walk::walk_property_key(self, it);
+  }
+}
+
+/// Adds the modifiers that are present to a node's name (ex. `Function(f) async`)
+/// since the name alone doesn't include them.
+fn with_modifiers(name: &str, modifiers: &[(&str, bool)]) -> String {
+  let mut signature = name.to_string();
+  for (modifier, _) in modifiers.iter().filter(|(_, is_present)| *is_present) {
+    signature.push(' ');
+    signature.push_str(modifier);
+  }
+  signature
+}
+
+/// Gets the text of a JSX text node the way it's rendered, where lines are
+/// trimmed and whitespace only lines are removed.
+fn jsx_text_value(raw: &str) -> String {
+  let lines = raw.split('\n').collect::<Vec<_>>();
+  let mut text = String::new();
+  for (i, line) in lines.iter().enumerate() {
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    let line = if i > 0 { line.trim_start() } else { line };
+    let line = if i < lines.len() - 1 { line.trim_end() } else { line };
+    if line.is_empty() {
+      continue;
+    }
+    if !text.is_empty() {
+      text.push(' ');
+    }
+    text.push_str(line);
+  }
+  text
+}
+
+fn collapse_whitespace(text: &str) -> String {
+  let mut result = String::with_capacity(text.len());
+  let mut last_was_whitespace = false;
+  for c in text.chars() {
+    if c.is_whitespace() {
+      if !last_was_whitespace {
+        result.push(' ');
+      }
+      last_was_whitespace = true;
+    } else {
+      result.push(c);
+      last_was_whitespace = false;
+    }
+  }
+  result
+}
+
+#[cfg(test)]
+mod test {
+  use oxc_allocator::Allocator;
+  use oxc_parser::Parser;
+  use oxc_span::SourceType;
+
+  use super::*;
+  use crate::configuration::SortImportsOptions;
+  use crate::format_text::build_parse_options;
+
+  fn difference(input: &str, output: &str, config: &Configuration) -> Option<(usize, String, String)> {
+    let allocator = Allocator::default();
+    let parse_options = build_parse_options(config);
+    let input = Parser::new(&allocator, input, SourceType::tsx())
+      .with_options(parse_options)
+      .parse();
+    let output = Parser::new(&allocator, output, SourceType::tsx())
+      .with_options(parse_options)
+      .parse();
+    find_semantic_difference(&input.program, &output.program, config)
+      .map(|difference| (difference.input_offset, difference.expected, difference.found))
+  }
+
+  #[test]
+  fn ignores_formatting_changes() {
+    let config = Configuration::default();
+    assert!(difference("let a = ((1 + 2)) * 3;;", "let a = (1 + 2) * 3;\n", &config).is_none());
+    assert!(difference("({'a': 1, 'b-c': 2})", "({ a: 1, \"b-c\": 2 });\n", &config).is_none());
+    assert!(
+      difference(
+        "<div>some text <b>bold</b> more</div>",
+        "<div>\n  some text <b>bold</b>{\" \"}\n  more\n</div>;\n",
+        &config
+      )
+      .is_none()
+    );
+  }
+
+  #[test]
+  fn finds_the_first_difference() {
+    let config = Configuration::default();
+    let (offset, expected, found) = difference("let a = b - c;", "let a = b + c;", &config).unwrap();
+    assert_eq!(offset, 8);
+    assert_eq!(expected, "BinaryExpression(-)");
+    assert_eq!(found, "BinaryExpression(+)");
+    let (_, expected, found) = difference("f(); g();", "f();", &config).unwrap();
+    assert_eq!(expected, "ExpressionStatement");
+    assert_eq!(found, "the end of the file");
+  }
+
+  #[test]
+  fn finds_changed_modifiers() {
+    let config = Configuration::default();
+    for (input, output, expected, found) in [
+      (
+        "let a = 1;",
+        "const a = 1;",
+        "VariableDeclaration(let)",
+        "VariableDeclaration(const)",
+      ),
+      (
+        "var a = 1;",
+        "let a = 1;",
+        "VariableDeclaration(var)",
+        "VariableDeclaration(let)",
+      ),
+      (
+        "async function f() {}",
+        "function f() {}",
+        "Function(f) async",
+        "Function(f)",
+      ),
+      (
+        "function* f() {}",
+        "function f() {}",
+        "Function(f) generator",
+        "Function(f)",
+      ),
+      (
+        "async () => {};",
+        "() => {};",
+        "ArrowFunctionExpression async",
+        "ArrowFunctionExpression",
+      ),
+      (
+        "class A { get a() {} }",
+        "class A { set a(v) {} }",
+        "MethodDefinition(Get)",
+        "MethodDefinition(Set)",
+      ),
+      (
+        "class A { static a() {} }",
+        "class A { a() {} }",
+        "MethodDefinition(Method) static",
+        "MethodDefinition(Method)",
+      ),
+      (
+        "class A { static a = 1; }",
+        "class A { a = 1; }",
+        "PropertyDefinition static",
+        "PropertyDefinition",
+      ),
+      (
+        "({ get a() {} });",
+        "({ a() {} });",
+        "ObjectProperty(a) Get",
+        "ObjectProperty(a) Init",
+      ),
+      (
+        "async function f() { for await (const a of b); }",
+        "async function f() { for (const a of b); }",
+        "ForOfStatement await",
+        "ForOfStatement",
+      ),
+      ("`a${b}c`;", "`a${b}d`;", "TemplateElement(c)", "TemplateElement(d)"),
+      (
+        "type A = keyof B[];",
+        "type A = readonly B[];",
+        "TSTypeOperator(keyof)",
+        "TSTypeOperator(readonly)",
+      ),
+      (
+        "import type { A } from \"a\";",
+        "import { A } from \"a\";",
+        "ImportDeclaration type",
+        "ImportDeclaration",
+      ),
+      (
+        "import { type A } from \"a\";",
+        "import { A } from \"a\";",
+        "ImportSpecifier(A) type",
+        "ImportSpecifier(A)",
+      ),
+      (
+        "export type { A };",
+        "export { A };",
+        "ExportNamedDeclaration type",
+        "ExportNamedDeclaration",
+      ),
+      (
+        "export { type A };",
+        "export { A };",
+        "ExportSpecifier(A) type",
+        "ExportSpecifier(A)",
+      ),
+      (
+        "export type { A } from \"a\";",
+        "export { A } from \"a\";",
+        "ExportFromDeclaration type",
+        "ExportFromDeclaration",
+      ),
+      (
+        "export type * from \"a\";",
+        "export * from \"a\";",
+        "ExportAllDeclaration type",
+        "ExportAllDeclaration",
+      ),
+    ] {
+      let (_, actual_expected, actual_found) = difference(input, output, &config).expect(input);
+      assert_eq!(
+        (actual_expected.as_str(), actual_found.as_str()),
+        (expected, found),
+        "{}",
+        input
+      );
+    }
+  }
+
+  #[test]
+  fn compares_sorted_imports_regardless_of_order() {
+    let input = "import { b, a } from \"b\";\nimport c from \"c\";\nimport d from \"a\";\n";
+    let output = "import d from \"a\";\nimport { a, b } from \"b\";\nimport c from \"c\";\n";
+    assert!(difference(input, output, &Configuration::default()).is_some());
+    let config = Configuration {
+      experimental_sort_imports: Some(SortImportsOptions::default()),
+      ..Default::default()
+    };
+    assert!(difference(input, output, &config).is_none());
+    assert!(
+      difference(
+        input,
+        "import d from \"a\";\nimport { a } from \"b\";\nimport c from \"c\";\n",
+        &config
+      )
+      .is_some()
+    );
+  }
+}
@@ -1,12 +1,15 @@
+use super::Formatter;
 use super::configuration::Configuration;
-use super::configuration::resolve_config;
+use super::format_cache::FormatCache;
+use super::panics::panic_error_message;
+use super::panics::panic_payload_text;
+use super::plugin_handler;
 
 use dprint_core::configuration::ConfigKeyMap;
 use dprint_core::configuration::GlobalConfiguration;
 use dprint_core::generate_plugin_code;
 use dprint_core::plugins::CheckConfigUpdatesMessage;
 use dprint_core::plugins::ConfigChange;
-use dprint_core::plugins::FileMatchingInfo;
 use dprint_core::plugins::FormatError;
 use dprint_core::plugins::FormatResult;
 use dprint_core::plugins::PluginInfo;
@@ -14,8 +17,36 @@ use dprint_core::plugins::PluginResolveConfigurationResult;
 use dprint_core::plugins::SyncFormatRequest;
 use dprint_core::plugins::SyncHostFormatRequest;
 use dprint_core::plugins::SyncPluginHandler;
+use dprint_core::plugins::wasm::WasiPrintFd;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::Once;
 
-struct OxcPluginHandler;
+thread_local! {
+  /// The file being formatted, for describing panics.
+  static CURRENT_FILE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+struct OxcPluginHandler {
+  /// Formatters by configuration fingerprint so the options are only built
+  /// once per configuration.
+  formatters: HashMap<u64, Formatter>,
+  format_cache: Arc<Mutex<FormatCache>>,
+}
+
+impl Default for OxcPluginHandler {
+  fn default() -> Self {
+    Self {
+      formatters: HashMap::new(),
+      format_cache: plugin_handler::create_format_cache(),
+    }
+  }
+}
 
 impl SyncPluginHandler<Configuration> for OxcPluginHandler {
   fn resolve_config(
@@ -23,48 +54,19 @@ impl SyncPluginHandler<Configuration> for OxcPluginHandler {
     config: ConfigKeyMap,
     global_config: &GlobalConfiguration,
   ) -> PluginResolveConfigurationResult<Configuration> {
-    let result = resolve_config(config, global_config);
-    let file_extensions = vec![
-      "ts".to_string(),
-      "tsx".to_string(),
-      "cts".to_string(),
-      "mts".to_string(),
-      "js".to_string(),
-      "jsx".to_string(),
-      "cjs".to_string(),
-      "mjs".to_string(),
-    ];
-    PluginResolveConfigurationResult {
-      config: result.config,
-      diagnostics: result.diagnostics,
-      file_matching: FileMatchingInfo {
-        file_extensions,
-        file_names: vec![],
-      },
-    }
+    plugin_handler::resolve_plugin_config(config, global_config)
   }
 
-  fn check_config_updates(&self, _message: CheckConfigUpdatesMessage) -> Result<Vec<ConfigChange>, FormatError> {
-    Ok(Vec::new())
+  fn check_config_updates(&self, message: CheckConfigUpdatesMessage) -> Result<Vec<ConfigChange>, FormatError> {
+    Ok(plugin_handler::check_config_updates(&message))
   }
 
   fn plugin_info(&mut self) -> PluginInfo {
-    let version = env!("CARGO_PKG_VERSION").to_string();
-    PluginInfo {
-      name: env!("CARGO_PKG_NAME").to_string(),
-      version: version.clone(),
-      config_key: "oxc".to_string(),
-      help_url: "https://dprint.dev/plugins/oxc".to_string(),
-      config_schema_url: format!(
-        "https://plugins.dprint.dev/dprint/dprint-plugin-oxc/{}/schema.json",
-        version
-      ),
-      update_url: Some("https://plugins.dprint.dev/dprint/dprint-plugin-oxc/latest.json".to_string()),
-    }
+    plugin_handler::plugin_info()
   }
 
   fn license_text(&mut self) -> String {
-    std::str::from_utf8(include_bytes!("../LICENSE")).unwrap().into()
+    plugin_handler::license_text()
   }
 
   fn format(
@@ -76,10 +78,36 @@ impl SyncPluginHandler<Configuration> for OxcPluginHandler {
       return Ok(None); // not implemented
     }
 
-    let text = String::from_utf8_lossy(&request.file_bytes);
-    let maybe_text = super::format_text(request.file_path, &text, request.config)?;
-    Ok(maybe_text.map(|t| t.into_bytes()))
+    // panics abort in Wasm, so record what was being formatted for the panic hook
+    INSTALL_PANIC_HOOK.call_once(install_panic_hook);
+    CURRENT_FILE.with(|file| *file.borrow_mut() = Some(request.file_path.display().to_string()));
+    let formatter = plugin_handler::get_or_create_formatter(&mut self.formatters, request.config, || {
+      plugin_handler::create_formatter(request.config, &self.format_cache)
+    });
+    let result = formatter.format_bytes(request.file_path, &request.file_bytes);
+    CURRENT_FILE.with(|file| file.borrow_mut().take());
+    Ok(result?)
   }
 }
 
-generate_plugin_code!(OxcPluginHandler, OxcPluginHandler);
+/// Writes panics to the host's stderr with the file being formatted and the
+/// Oxc version before deferring to the previous hook.
+///
+/// Panics abort in Wasm, so the format call traps instead of returning an
+/// error and this output is the only place the host sees which file failed.
+fn install_panic_hook() {
+  let previous_hook = std::panic::take_hook();
+  std::panic::set_hook(Box::new(move |info| {
+    let file = CURRENT_FILE
+      .with(|file| file.borrow().clone())
+      .unwrap_or_else(|| "an unknown file".to_string());
+    let mut message = panic_payload_text(info.payload()).to_string();
+    if let Some(location) = info.location() {
+      message.push_str(&format!(" ({})", location));
+    }
+    let _ = writeln!(WasiPrintFd(2), "{}", panic_error_message(&file, &message));
+    previous_hook(info);
+  }));
+}
+
+generate_plugin_code!(OxcPluginHandler, OxcPluginHandler::default());
@@ -0,0 +1,77 @@
+#![cfg(feature = "process")]
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use dprint_core::configuration::ConfigKeyMap;
+use dprint_core::configuration::ConfigKeyValue;
+use dprint_core::configuration::GlobalConfiguration;
+use dprint_core::plugins::FormatConfigId;
+use dprint_core::plugins::NullCancellationToken;
+use dprint_core::plugins::process::ProcessPluginCommunicator;
+use dprint_core::plugins::process::ProcessPluginCommunicatorFormatRequest;
+
+async fn start_plugin() -> ProcessPluginCommunicator {
+  ProcessPluginCommunicator::new(Path::new(env!("CARGO_BIN_EXE_dprint-plugin-oxc-process")), |line| {
+    eprintln!("{}", line)
+  })
+  .await
+  .unwrap()
+}
+
+fn format_request(config_id: FormatConfigId, file_path: &str, text: &str) -> ProcessPluginCommunicatorFormatRequest {
+  ProcessPluginCommunicatorFormatRequest {
+    file_path: PathBuf::from(file_path),
+    file_bytes: text.as_bytes().to_vec(),
+    range: None,
+    config_id,
+    override_config: ConfigKeyMap::new(),
+    on_host_format: Arc::new(|_| Box::pin(async { Ok(None) })),
+    token: Arc::new(NullCancellationToken),
+  }
+}
+
+#[tokio::test]
+async fn formats_over_the_process_plugin_protocol() {
+  let communicator = start_plugin().await;
+
+  let plugin_info = communicator.plugin_info().await.unwrap();
+  assert_eq!(plugin_info.config_key, "oxc");
+  assert!(communicator.license_text().await.unwrap().contains("MIT"));
+
+  let config_id = FormatConfigId::from_raw(1);
+  let mut config = ConfigKeyMap::new();
+  config.insert("semicolons".to_string(), ConfigKeyValue::from_str("asNeeded"));
+  config.insert("fileExtensions".to_string(), {
+    let mut extensions = ConfigKeyMap::new();
+    extensions.insert("es6".to_string(), ConfigKeyValue::from_str("js"));
+    ConfigKeyValue::Object(extensions)
+  });
+  communicator
+    .register_config(config_id, &GlobalConfiguration::default(), &config)
+    .await
+    .unwrap();
+  assert!(communicator.config_diagnostics(config_id).await.unwrap().is_empty());
+  let file_matching = communicator.file_matching_info(config_id).await.unwrap();
+  assert!(file_matching.file_extensions.contains(&"es6".to_string()));
+
+  let result = communicator
+    .format_text(format_request(config_id, "file.ts", "const x=1;"))
+    .await
+    .unwrap();
+  assert_eq!(result.unwrap(), b"const x = 1\n");
+  let result = communicator
+    .format_text(format_request(config_id, "file.ts", "const x = 1\n"))
+    .await
+    .unwrap();
+  assert!(result.is_none());
+
+  let err = communicator
+    .format_text(format_request(config_id, "file.ts", "const x ="))
+    .await
+    .unwrap_err();
+  assert!(!err.to_string().is_empty());
+
+  communicator.shutdown().await;
+}
@@ -0,0 +1,193 @@
+extern crate dprint_plugin_oxc;
+
+use std::collections::BTreeSet;
+
+use dprint_core::configuration::*;
+use dprint_plugin_oxc::configuration::ConfigValueSource;
+use dprint_plugin_oxc::configuration::DefaultValue;
+use dprint_plugin_oxc::configuration::PropertyDescription;
+use dprint_plugin_oxc::configuration::PropertyKind;
+use dprint_plugin_oxc::configuration::explain_config;
+use dprint_plugin_oxc::configuration::generate_json_schema;
+use dprint_plugin_oxc::configuration::generate_typescript_declarations;
+use dprint_plugin_oxc::configuration::get_property_descriptions;
+use dprint_plugin_oxc::configuration::resolve_config;
+
+const SCHEMA_PATH: &str = "./deployment/schema.json";
+const TYPESCRIPT_DECLARATIONS_PATH: &str = "./deployment/npm/config.d.ts";
+/// Keys `resolve_config` still accepts that are left out of the schema.
+const LEGACY_KEYS: &[&str] = &["indentSize", "trailingComma"];
+
+#[test]
+fn schema_is_up_to_date() {
+  let generated = serde_json::to_value(generate_json_schema()).unwrap();
+  if std::env::var("UPDATE_SCHEMA").is_ok() {
+    let text = serde_json::to_string_pretty(&generated).unwrap();
+    std::fs::write(SCHEMA_PATH, text + "\n").unwrap();
+  }
+
+  let committed: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(SCHEMA_PATH).unwrap()).unwrap();
+  assert!(
+    committed == generated,
+    "{} is out of date. Run `UPDATE_SCHEMA=1 cargo test --test schema` to regenerate it.",
+    SCHEMA_PATH
+  );
+}
+
+#[test]
+fn typescript_declarations_are_up_to_date() {
+  let generated = generate_typescript_declarations();
+  if std::env::var("UPDATE_SCHEMA").is_ok() {
+    std::fs::write(TYPESCRIPT_DECLARATIONS_PATH, &generated).unwrap();
+  }
+
+  let committed = std::fs::read_to_string(TYPESCRIPT_DECLARATIONS_PATH).unwrap();
+  assert!(
+    committed.replace("\r\n", "\n") == generated,
+    "{} is out of date. Run `UPDATE_SCHEMA=1 cargo test --test schema` to regenerate it.",
+    TYPESCRIPT_DECLARATIONS_PATH
+  );
+}
+
+#[test]
+fn schema_defaults_resolve_without_diagnostics() {
+  fn build_defaults(properties: Vec<PropertyDescription>) -> serde_json::Map<String, serde_json::Value> {
+    let mut map = serde_json::Map::new();
+    for property in properties {
+      let value = match (property.default, property.kind) {
+        (Some(default), _) => serde_json::to_value(default).unwrap(),
+        (None, PropertyKind::Object(properties)) => serde_json::Value::Object(build_defaults(properties)),
+        (None, _) => continue,
+      };
+      map.insert(property.name.to_string(), value);
+    }
+    map
+  }
+
+  let config: ConfigKeyMap =
+    serde_json::from_value(serde_json::Value::Object(build_defaults(get_property_descriptions()))).unwrap();
+  let result = resolve_config(config, &GlobalConfiguration::default());
+  let diagnostics = result
+    .diagnostics
+    .iter()
+    .map(|d| format!("{}: {}", d.property_name, d.message))
+    .collect::<Vec<_>>();
+  assert_eq!(diagnostics, Vec::<String>::new());
+}
+
+#[test]
+fn schema_describes_every_resolved_key() {
+  #[derive(Clone)]
+  enum Step<'a> {
+    Property(&'a str),
+    ArrayItem,
+    MapValue,
+  }
+
+  fn collect_objects<'a>(
+    kind: &'a PropertyKind,
+    steps: Vec<Step<'a>>,
+    objects: &mut Vec<(Vec<Step<'a>>, &'a [PropertyDescription])>,
+  ) {
+    let with_step = |step: Step<'a>| {
+      let mut steps = steps.clone();
+      steps.push(step);
+      steps
+    };
+    match kind {
+      PropertyKind::Object(properties) => {
+        for property in properties {
+          collect_objects(&property.kind, with_step(Step::Property(property.name)), objects);
+        }
+        objects.push((steps, properties));
+      }
+      PropertyKind::Array(item) => collect_objects(item, with_step(Step::ArrayItem), objects),
+      PropertyKind::Map(value) => collect_objects(value, with_step(Step::MapValue), objects),
+      PropertyKind::Union(kinds) => {
+        for kind in kinds {
+          collect_objects(kind, steps.clone(), objects);
+        }
+      }
+      _ => {}
+    }
+  }
+
+  // every key resolve_config reads appears as a string literal in it
+  let source = include_str!("../src/configuration/resolve_config.rs");
+  let source = &source[..source.find("#[cfg(test)]").unwrap()];
+  let candidates = source
+    .split('"')
+    .skip(1)
+    .step_by(2)
+    .filter(|text| !text.is_empty() && text.chars().all(|c| c.is_ascii_alphanumeric()))
+    .collect::<BTreeSet<_>>();
+
+  let root = PropertyKind::Object(get_property_descriptions());
+  let mut objects = Vec::new();
+  collect_objects(&root, Vec::new(), &mut objects);
+  for (steps, properties) in objects {
+    let mut path = String::new();
+    for step in &steps {
+      match step {
+        Step::Property(name) if path.is_empty() => path.push_str(name),
+        Step::Property(name) => path.push_str(&format!(".{}", name)),
+        Step::ArrayItem => path.push_str("[0]"),
+        Step::MapValue => path.push_str(".name"),
+      }
+    }
+
+    for candidate in &candidates {
+      let object = |key: &str, value| serde_json::Value::Object(serde_json::Map::from_iter([(key.to_string(), value)]));
+      let mut value = object(*candidate, serde_json::Value::Null);
+      for step in steps.iter().rev() {
+        value = match step {
+          Step::Property(name) => object(*name, value),
+          Step::ArrayItem => serde_json::Value::Array(vec![value]),
+          Step::MapValue => object("name", value),
+        };
+      }
+      let candidate_path = if path.is_empty() {
+        candidate.to_string()
+      } else {
+        format!("{}.{}", path, candidate)
+      };
+      let config: ConfigKeyMap = serde_json::from_value(value).unwrap();
+      let is_unknown = resolve_config(config, &GlobalConfiguration::default())
+        .diagnostics
+        .iter()
+        .any(|d| d.property_name == candidate_path && d.message.starts_with("Unknown property"));
+      if is_unknown || (steps.is_empty() && LEGACY_KEYS.contains(candidate)) {
+        continue;
+      }
+      assert!(
+        properties.iter().any(|property| property.name == *candidate),
+        "{} is accepted by resolve_config, but is missing from the schema.",
+        candidate_path
+      );
+    }
+  }
+}
+
+#[test]
+fn schema_defaults_match_oxc_defaults() {
+  let explained = explain_config(ConfigKeyMap::new(), &GlobalConfiguration::default());
+  for property in get_property_descriptions() {
+    let Some(explained_value) = explained.get(property.name) else {
+      continue;
+    };
+    if explained_value.source != ConfigValueSource::OxcDefault {
+      continue;
+    }
+    let default_text = match property.default {
+      Some(DefaultValue::Bool(value)) => value.to_string(),
+      Some(DefaultValue::Number(value)) => value.to_string(),
+      Some(DefaultValue::String(value)) => value,
+      _ => continue,
+    };
+    assert_eq!(
+      explained_value.value, default_text,
+      "Schema default of {} differs from Oxc's default.",
+      property.name
+    );
+  }
+}
@@ -7,6 +7,7 @@ use std::sync::Arc;
 use dprint_core::configuration::*;
 use dprint_development::*;
 use dprint_plugin_oxc::configuration::Configuration;
+use dprint_plugin_oxc::configuration::NestingDepthExceeded;
 use dprint_plugin_oxc::configuration::resolve_config;
 use dprint_plugin_oxc::*;
 
@@ -44,3 +45,26 @@ fn should_fail_on_parse_error_js() {
   // Just verify that it returns an error for invalid syntax
   assert!(!err.to_string().is_empty());
 }
+
+#[test]
+fn should_fail_on_deeply_nested_input() {
+  let config = Configuration::default();
+  let deep_array = format!("const x = {}1{};", "[".repeat(100_000), "]".repeat(100_000));
+  let binary_chain = format!("const x = 1{};", " + 1".repeat(5_000));
+  let jsx = format!("const x = {}{};", "<div>".repeat(1_000), "</div>".repeat(1_000));
+  for (file_name, text) in [("file.ts", deep_array), ("file.ts", binary_chain), ("file.tsx", jsx)] {
+    let err = format_text(&PathBuf::from(file_name), &text, &config).unwrap_err();
+    assert!(err.to_string().contains("nested more than 500 levels deep"), "{}", err);
+  }
+
+  let config = Configuration {
+    nesting_depth_exceeded: Some(NestingDepthExceeded::Skip),
+    ..Default::default()
+  };
+  let text = format!("const x = 1{};", " + 1".repeat(5_000));
+  assert!(
+    format_text(&PathBuf::from("file.ts"), &text, &config)
+      .unwrap()
+      .is_none()
+  );
+}